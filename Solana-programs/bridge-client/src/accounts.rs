@@ -0,0 +1,42 @@
+use anchor_lang::{AccountDeserialize, Discriminator, Result};
+pub use bridge::program_accounts::{
+    ChainConfig, GlobalAccount, OrderAccount, TokenConfig, TokenRegistry, TokenRoute, ValidatorSet,
+};
+
+/// The first 8 bytes of every account of type `T`, e.g. for RPC filters.
+pub fn discriminator<T: Discriminator>() -> [u8; 8] {
+    T::discriminator()
+}
+
+/// Decodes the data of a bridge account, discriminator included.
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn global_account(data: &[u8]) -> Result<GlobalAccount> {
+    decode(data)
+}
+
+pub fn validator_set(data: &[u8]) -> Result<ValidatorSet> {
+    decode(data)
+}
+
+pub fn token_config(data: &[u8]) -> Result<TokenConfig> {
+    decode(data)
+}
+
+pub fn token_registry(data: &[u8]) -> Result<TokenRegistry> {
+    decode(data)
+}
+
+pub fn order(data: &[u8]) -> Result<OrderAccount> {
+    decode(data)
+}
+
+pub fn chain_config(data: &[u8]) -> Result<ChainConfig> {
+    decode(data)
+}
+
+pub fn token_route(data: &[u8]) -> Result<TokenRoute> {
+    decode(data)
+}
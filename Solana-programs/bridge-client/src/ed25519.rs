@@ -0,0 +1,56 @@
+//! Builds Ed25519 program instructions the bridge accepts as validator
+//! signatures: every signature covers the same message, stored once, and all
+//! offsets point into the instruction itself.
+use solana_program::ed25519_program;
+use solana_program::instruction::Instruction;
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+const PUBKEY_SIZE: usize = 32;
+const SIGNATURE_SIZE: usize = 64;
+
+/// A validator public key and its signature over the message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidatorSignature {
+    pub pubkey: [u8; 32],
+    pub signature: [u8; 64],
+}
+
+/// Returns the instruction verifying `signatures` over `message`, to be placed
+/// anywhere in the same transaction as the bridge instruction.
+pub fn new_verify_instruction(signatures: &[ValidatorSignature], message: &[u8]) -> Instruction {
+    assert!(signatures.len() <= u8::MAX as usize, "too many signatures");
+    let data_start = SIGNATURE_OFFSETS_START + signatures.len() * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+    let message_offset = data_start + signatures.len() * (PUBKEY_SIZE + SIGNATURE_SIZE);
+    assert!(message_offset + message.len() <= u16::MAX as usize, "message too long");
+
+    let mut data = vec![signatures.len() as u8, 0];
+    for i in 0..signatures.len() {
+        let public_key_offset = data_start + i * (PUBKEY_SIZE + SIGNATURE_SIZE);
+        let signature_offset = public_key_offset + PUBKEY_SIZE;
+        for value in [
+            signature_offset,
+            u16::MAX as usize,
+            public_key_offset,
+            u16::MAX as usize,
+            message_offset,
+            message.len(),
+            u16::MAX as usize,
+        ]
+        .iter()
+        {
+            data.extend_from_slice(&(*value as u16).to_le_bytes());
+        }
+    }
+    for signature in signatures {
+        data.extend_from_slice(&signature.pubkey);
+        data.extend_from_slice(&signature.signature);
+    }
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::id(),
+        accounts: vec![],
+        data,
+    }
+}
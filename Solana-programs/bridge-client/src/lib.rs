@@ -0,0 +1,15 @@
+//! Off-chain helpers for the `bridge` program: PDA derivation, the byte
+//! layouts validators sign, Ed25519 and Secp256k1 verify instructions and
+//! account decoding.
+//!
+//! Integer fields are taken as `u64`/`i64` and encoded to the big-endian
+//! arrays the program expects.
+pub mod accounts;
+pub mod ed25519;
+pub mod messages;
+pub mod pda;
+pub mod secp256k1;
+
+pub use bridge::messages::Domain;
+pub use bridge::program_accounts::SignatureScheme;
+pub use bridge::ID as PROGRAM_ID;
@@ -0,0 +1,239 @@
+//! Typed builders for the messages validators sign, encoded by the program's
+//! own `bridge::messages` so both sides always agree on the layout.
+//!
+//! Amounts are canonical, with `CANONICAL_DECIMALS` decimals whatever the
+//! token; `canonical_amount` converts from local token units.
+use anchor_lang::Result;
+use bridge::messages::{self, Domain};
+use bridge::program_accounts::SignatureScheme;
+pub use bridge::utils::CANONICAL_DECIMALS;
+use bridge::utils::{from_canonical_amount, to_canonical_amount};
+use solana_program::pubkey::Pubkey;
+
+/// The 32-byte big-endian form the program takes canonical amounts in.
+pub fn encode_amount(amount: u128) -> [u8; 32] {
+    let mut encoded = [0u8; 32];
+    encoded[16..].copy_from_slice(&amount.to_be_bytes());
+    encoded
+}
+
+/// The canonical form of `amount` units of a token with `decimals`.
+pub fn canonical_amount(amount: u64, decimals: u8) -> Result<u128> {
+    let encoded = to_canonical_amount(amount, decimals)?;
+    let mut value = [0u8; 16];
+    value.copy_from_slice(&encoded[16..]);
+    Ok(u128::from_be_bytes(value))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplyTokenMessage {
+    pub order_id: [u8; 32],
+    pub applicant: Pubkey,
+    pub receipient: [u8; 32],
+    pub from_chain_id: u64,
+    pub from_token: Pubkey,
+    /// Canonical amount.
+    pub amount: u128,
+    pub to_chain_id: u64,
+    pub deadline: i64,
+}
+
+impl ApplyTokenMessage {
+    pub fn encode(&self, scheme: SignatureScheme, domain: &Domain) -> Vec<u8> {
+        messages::apply_token_message(
+            scheme,
+            domain,
+            &self.order_id,
+            &self.applicant.to_bytes(),
+            &self.receipient,
+            &self.from_chain_id.to_be_bytes(),
+            &self.from_token.to_bytes(),
+            &encode_amount(self.amount),
+            &self.to_chain_id.to_be_bytes(),
+            &self.deadline.to_be_bytes(),
+        )
+    }
+
+    /// The legacy layout, with the amount in units of a token with `decimals`.
+    pub fn encode_legacy(&self, scheme: SignatureScheme, decimals: u8) -> Result<Vec<u8>> {
+        let amount = from_canonical_amount(&encode_amount(self.amount), decimals)?;
+        Ok(messages::legacy_apply_token_message(
+            scheme,
+            &self.order_id,
+            &self.applicant.to_bytes(),
+            &self.receipient,
+            &self.from_chain_id.to_be_bytes(),
+            &self.from_token.to_bytes(),
+            &amount.to_be_bytes(),
+            &self.to_chain_id.to_be_bytes(),
+            &self.deadline.to_be_bytes(),
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimTokenMessage {
+    pub order_id: [u8; 32],
+    pub applicant: [u8; 32],
+    pub receipient: Pubkey,
+    pub from_chain_id: u64,
+    pub to_chain_id: u64,
+    pub to_token: Pubkey,
+    /// Canonical amount.
+    pub amount: u128,
+    pub deadline: i64,
+}
+
+impl ClaimTokenMessage {
+    pub fn encode(&self, scheme: SignatureScheme, domain: &Domain) -> Vec<u8> {
+        messages::claim_token_message(
+            scheme,
+            domain,
+            &self.order_id,
+            &self.applicant,
+            &self.receipient.to_bytes(),
+            &self.from_chain_id.to_be_bytes(),
+            &self.to_chain_id.to_be_bytes(),
+            &self.to_token.to_bytes(),
+            &encode_amount(self.amount),
+            &self.deadline.to_be_bytes(),
+        )
+    }
+
+    /// The legacy layout, with the amount in units of a token with `decimals`.
+    pub fn encode_legacy(&self, scheme: SignatureScheme, decimals: u8) -> Result<Vec<u8>> {
+        let amount = from_canonical_amount(&encode_amount(self.amount), decimals)?;
+        Ok(messages::legacy_claim_token_message(
+            scheme,
+            &self.order_id,
+            &self.applicant,
+            &self.receipient.to_bytes(),
+            &self.to_chain_id.to_be_bytes(),
+            &self.to_token.to_bytes(),
+            &amount.to_be_bytes(),
+            &self.deadline.to_be_bytes(),
+        ))
+    }
+
+    /// The message for `claim_token_and_call`, which hands the claimed tokens
+    /// to `target_program` together with `payload`.
+    pub fn encode_call(
+        &self,
+        scheme: SignatureScheme,
+        domain: &Domain,
+        target_program: &Pubkey,
+        payload: &[u8],
+    ) -> Vec<u8> {
+        messages::claim_and_call_message(
+            scheme,
+            domain,
+            &self.order_id,
+            &self.applicant,
+            &self.receipient.to_bytes(),
+            &self.from_chain_id.to_be_bytes(),
+            &self.to_chain_id.to_be_bytes(),
+            &self.to_token.to_bytes(),
+            &encode_amount(self.amount),
+            &self.deadline.to_be_bytes(),
+            &target_program.to_bytes(),
+            payload,
+        )
+    }
+
+    /// The message a relayer submits through `deliver_token`, with a canonical
+    /// `relayer_fee`.
+    pub fn encode_delivery(&self, scheme: SignatureScheme, domain: &Domain, relayer_fee: u128) -> Vec<u8> {
+        messages::deliver_token_message(
+            scheme,
+            domain,
+            &self.order_id,
+            &self.applicant,
+            &self.receipient.to_bytes(),
+            &self.from_chain_id.to_be_bytes(),
+            &self.to_chain_id.to_be_bytes(),
+            &self.to_token.to_bytes(),
+            &encode_amount(self.amount),
+            &self.deadline.to_be_bytes(),
+            &encode_amount(relayer_fee),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefundApplyMessage {
+    pub order_id: [u8; 32],
+    pub applicant: Pubkey,
+    pub refund_fee: bool,
+    pub deadline: i64,
+}
+
+impl RefundApplyMessage {
+    pub fn encode(&self, scheme: SignatureScheme, domain: &Domain) -> Vec<u8> {
+        messages::refund_apply_message(
+            scheme,
+            domain,
+            &self.order_id,
+            &self.applicant.to_bytes(),
+            self.refund_fee,
+            &self.deadline.to_be_bytes(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplyNftMessage {
+    pub order_id: [u8; 32],
+    pub applicant: Pubkey,
+    pub receipient: [u8; 32],
+    pub from_chain_id: u64,
+    pub collection: Pubkey,
+    pub mint: Pubkey,
+    pub to_chain_id: u64,
+    pub deadline: i64,
+}
+
+impl ApplyNftMessage {
+    pub fn encode(&self, scheme: SignatureScheme, domain: &Domain) -> Vec<u8> {
+        messages::apply_nft_message(
+            scheme,
+            domain,
+            &self.order_id,
+            &self.applicant.to_bytes(),
+            &self.receipient,
+            &self.from_chain_id.to_be_bytes(),
+            &self.collection.to_bytes(),
+            &self.mint.to_bytes(),
+            &self.to_chain_id.to_be_bytes(),
+            &self.deadline.to_be_bytes(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimNftMessage {
+    pub order_id: [u8; 32],
+    pub applicant: [u8; 32],
+    pub receipient: Pubkey,
+    pub from_chain_id: u64,
+    pub to_chain_id: u64,
+    pub collection: Pubkey,
+    pub mint: Pubkey,
+    pub deadline: i64,
+}
+
+impl ClaimNftMessage {
+    pub fn encode(&self, scheme: SignatureScheme, domain: &Domain) -> Vec<u8> {
+        messages::claim_nft_message(
+            scheme,
+            domain,
+            &self.order_id,
+            &self.applicant,
+            &self.receipient.to_bytes(),
+            &self.from_chain_id.to_be_bytes(),
+            &self.to_chain_id.to_be_bytes(),
+            &self.collection.to_bytes(),
+            &self.mint.to_bytes(),
+            &self.deadline.to_be_bytes(),
+        )
+    }
+}
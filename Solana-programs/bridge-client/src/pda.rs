@@ -0,0 +1,77 @@
+use solana_program::pubkey::Pubkey;
+
+fn find(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &bridge::ID)
+}
+
+pub fn global_account() -> (Pubkey, u8) {
+    find(&[b"global-account-02"])
+}
+
+pub fn validator_set() -> (Pubkey, u8) {
+    find(&[b"validator-set"])
+}
+
+pub fn vault(mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"vault", mint.as_ref()])
+}
+
+pub fn token_config(mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"token-config", mint.as_ref()])
+}
+
+pub fn token_registry() -> (Pubkey, u8) {
+    find(&[b"token-registry"])
+}
+
+pub fn emergency_withdrawal(mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"emergency-withdrawal", mint.as_ref()])
+}
+
+pub fn mint_authority(mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"mint-authority", mint.as_ref()])
+}
+
+pub fn chain_config(chain_id: u64) -> (Pubkey, u8) {
+    find(&[b"chain", &chain_id.to_be_bytes()])
+}
+
+pub fn token_route(mint: &Pubkey, chain_id: u64) -> (Pubkey, u8) {
+    find(&[b"route", mint.as_ref(), &chain_id.to_be_bytes()])
+}
+
+pub fn apply_order(order_id: &[u8; 32]) -> (Pubkey, u8) {
+    find(&[b"apply-order", order_id])
+}
+
+pub fn claim_order(order_id: &[u8; 32]) -> (Pubkey, u8) {
+    find(&[b"claim-order", order_id])
+}
+
+pub fn unwrap_account(order_id: &[u8; 32]) -> (Pubkey, u8) {
+    find(&[b"unwrap", order_id])
+}
+
+pub fn call_target(program_id: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"call-target", program_id.as_ref()])
+}
+
+pub fn call_authority(target_program: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"call-authority", target_program.as_ref(), mint.as_ref()])
+}
+
+pub fn call_account(target_program: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"call-account", target_program.as_ref(), mint.as_ref()])
+}
+
+pub fn nft_config() -> (Pubkey, u8) {
+    find(&[b"nft-config"])
+}
+
+pub fn supported_collection(collection: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"nft-collection", collection.as_ref()])
+}
+
+pub fn nft_escrow(mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"nft-escrow", mint.as_ref()])
+}
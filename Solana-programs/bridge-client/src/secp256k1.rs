@@ -0,0 +1,71 @@
+//! Builds Secp256k1 program instructions the bridge accepts as validator
+//! signatures when the validator set uses `SignatureScheme::Secp256k1`: every
+//! signature covers the same message, stored once, and all offsets point into
+//! the instruction itself.
+use solana_program::instruction::Instruction;
+use solana_program::keccak;
+use solana_program::secp256k1_program;
+
+const SIGNATURE_OFFSETS_START: usize = 1;
+const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 11;
+const ETH_ADDRESS_SIZE: usize = 20;
+const SIGNATURE_SIZE: usize = 64;
+
+/// A validator Ethereum address and its recoverable signature over the
+/// keccak256 hash of the message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidatorSignature {
+    pub eth_address: [u8; 20],
+    pub signature: [u8; 64],
+    pub recovery_id: u8,
+}
+
+/// The Ethereum address of an uncompressed secp256k1 public key, without its
+/// leading `0x04` tag.
+pub fn eth_address(pubkey: &[u8; 64]) -> [u8; 20] {
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&keccak::hash(pubkey).to_bytes()[12..]);
+    address
+}
+
+/// `eth_address` left-padded to 32 bytes, as the validator set stores it.
+pub fn validator_key(eth_address: &[u8; 20]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    key[32 - ETH_ADDRESS_SIZE..].copy_from_slice(eth_address);
+    key
+}
+
+/// Returns the instruction verifying `signatures` over `message`. Secp256k1
+/// offsets name their instruction by absolute index, so `instruction_index`
+/// must be the position of this instruction in the transaction.
+pub fn new_verify_instruction(signatures: &[ValidatorSignature], message: &[u8], instruction_index: u8) -> Instruction {
+    assert!(signatures.len() <= u8::MAX as usize, "too many signatures");
+    let data_start = SIGNATURE_OFFSETS_START + signatures.len() * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+    let message_offset = data_start + signatures.len() * (ETH_ADDRESS_SIZE + SIGNATURE_SIZE + 1);
+    assert!(message_offset + message.len() <= u16::MAX as usize, "message too long");
+
+    let mut data = vec![signatures.len() as u8];
+    for i in 0..signatures.len() {
+        let eth_address_offset = data_start + i * (ETH_ADDRESS_SIZE + SIGNATURE_SIZE + 1);
+        let signature_offset = eth_address_offset + ETH_ADDRESS_SIZE;
+        data.extend_from_slice(&(signature_offset as u16).to_le_bytes());
+        data.push(instruction_index);
+        data.extend_from_slice(&(eth_address_offset as u16).to_le_bytes());
+        data.push(instruction_index);
+        data.extend_from_slice(&(message_offset as u16).to_le_bytes());
+        data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+        data.push(instruction_index);
+    }
+    for signature in signatures {
+        data.extend_from_slice(&signature.eth_address);
+        data.extend_from_slice(&signature.signature);
+        data.push(signature.recovery_id);
+    }
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: secp256k1_program::id(),
+        accounts: vec![],
+        data,
+    }
+}
@@ -0,0 +1,200 @@
+use anchor_lang::AccountSerialize;
+use bridge::program_accounts::{FeeMode, FeeRounding, GlobalAccount};
+use bridge::utils::{check_ed25519_data, check_secp256k1_data};
+use bridge_client::ed25519::{new_verify_instruction, ValidatorSignature};
+use bridge_client::messages::{canonical_amount, ApplyTokenMessage, ClaimTokenMessage, RefundApplyMessage};
+use bridge_client::secp256k1;
+use bridge_client::{accounts, pda, Domain, SignatureScheme, PROGRAM_ID};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use solana_program::keccak;
+use solana_program::pubkey::Pubkey;
+use std::convert::TryInto;
+
+fn validator(seed: u8) -> Keypair {
+    let secret = SecretKey::from_bytes(&[seed; 32]).unwrap();
+    let public = PublicKey::from(&secret);
+    Keypair { secret, public }
+}
+
+fn sign(keypair: &Keypair, message: &[u8]) -> ValidatorSignature {
+    ValidatorSignature {
+        pubkey: keypair.public.to_bytes(),
+        signature: keypair.sign(message).to_bytes(),
+    }
+}
+
+fn domain() -> Domain {
+    Domain {
+        program_id: PROGRAM_ID,
+        chain_id: 101,
+    }
+}
+
+fn apply_message() -> ApplyTokenMessage {
+    ApplyTokenMessage {
+        order_id: [1; 32],
+        applicant: Pubkey::new_from_array([2; 32]),
+        receipient: [3; 32],
+        from_chain_id: 101,
+        from_token: Pubkey::new_from_array([4; 32]),
+        amount: 1_000_000_000_000_000_000,
+        to_chain_id: 56,
+        deadline: 1_700_000_000,
+    }
+}
+
+#[test]
+fn apply_message_passes_check_ed25519_data() {
+    let message = apply_message().encode(SignatureScheme::Ed25519, &domain());
+    let validators = [validator(7), validator(8), validator(9)];
+    let signatures: Vec<_> = validators.iter().map(|keypair| sign(keypair, &message)).collect();
+    let ix = new_verify_instruction(&signatures, &message);
+
+    let signers = check_ed25519_data(&ix.data, &message).unwrap();
+    let expected: Vec<[u8; 32]> = validators.iter().map(|keypair| keypair.public.to_bytes()).collect();
+    assert_eq!(signers, expected);
+}
+
+#[test]
+fn claim_and_refund_messages_pass_check_ed25519_data() {
+    let claim = ClaimTokenMessage {
+        order_id: [5; 32],
+        applicant: [6; 32],
+        receipient: Pubkey::new_from_array([7; 32]),
+        from_chain_id: 56,
+        to_chain_id: 101,
+        to_token: Pubkey::new_from_array([8; 32]),
+        amount: 42_000_000_000_000,
+        deadline: 1_700_000_000,
+    };
+    let refund = RefundApplyMessage {
+        order_id: [9; 32],
+        applicant: Pubkey::new_from_array([10; 32]),
+        refund_fee: true,
+        deadline: 1_700_000_000,
+    };
+    let keypair = validator(11);
+    for message in [
+        claim.encode(SignatureScheme::Ed25519, &domain()),
+        claim.encode_legacy(SignatureScheme::Ed25519, 6).unwrap(),
+        claim.encode_delivery(SignatureScheme::Ed25519, &domain(), 2_000_000_000_000),
+        refund.encode(SignatureScheme::Ed25519, &domain()),
+    ]
+    .iter()
+    {
+        let ix = new_verify_instruction(&[sign(&keypair, message)], message);
+        assert_eq!(check_ed25519_data(&ix.data, message).unwrap(), vec![keypair.public.to_bytes()]);
+    }
+}
+
+#[test]
+fn apply_message_passes_check_secp256k1_data() {
+    let message = apply_message().encode(SignatureScheme::Secp256k1, &domain());
+    let signatures: Vec<_> = [21u8, 22]
+        .iter()
+        .map(|seed| {
+            let secret = libsecp256k1::SecretKey::parse(&[*seed; 32]).unwrap();
+            let pubkey = libsecp256k1::PublicKey::from_secret_key(&secret).serialize();
+            let hash = libsecp256k1::Message::parse(&keccak::hash(&message).to_bytes());
+            let (signature, recovery_id) = libsecp256k1::sign(&hash, &secret);
+            secp256k1::ValidatorSignature {
+                eth_address: secp256k1::eth_address(pubkey[1..].try_into().unwrap()),
+                signature: signature.serialize(),
+                recovery_id: recovery_id.serialize(),
+            }
+        })
+        .collect();
+    let ix = secp256k1::new_verify_instruction(&signatures, &message, 3);
+
+    let signers = check_secp256k1_data(&ix.data, &message, 3).unwrap();
+    let expected: Vec<[u8; 32]> = signatures
+        .iter()
+        .map(|signature| secp256k1::validator_key(&signature.eth_address))
+        .collect();
+    assert_eq!(signers, expected);
+    // Offsets name instruction 3, so the same data read at another index
+    // points outside of it.
+    assert!(check_secp256k1_data(&ix.data, &message, 0).is_err());
+}
+
+#[test]
+fn signatures_over_another_message_are_ignored() {
+    let message = apply_message().encode(SignatureScheme::Ed25519, &domain());
+    let other_domain = Domain {
+        program_id: PROGRAM_ID,
+        chain_id: 102,
+    };
+    let other = apply_message().encode(SignatureScheme::Ed25519, &other_domain);
+    let legacy = apply_message().encode_legacy(SignatureScheme::Ed25519, 9).unwrap();
+    let keypair = validator(12);
+
+    let ix = new_verify_instruction(&[sign(&keypair, &message)], &message);
+    assert!(check_ed25519_data(&ix.data, &other).unwrap().is_empty());
+    assert!(check_ed25519_data(&ix.data, &legacy).unwrap().is_empty());
+}
+
+#[test]
+fn legacy_apply_message_is_raw_concatenation() {
+    let message = apply_message();
+    let mut expected = Vec::new();
+    expected.extend_from_slice(&message.order_id);
+    expected.extend_from_slice(message.applicant.as_ref());
+    expected.extend_from_slice(&message.receipient);
+    expected.extend_from_slice(&message.from_chain_id.to_be_bytes());
+    expected.extend_from_slice(message.from_token.as_ref());
+    expected.extend_from_slice(&1_000_000_000u64.to_be_bytes());
+    expected.extend_from_slice(&message.to_chain_id.to_be_bytes());
+    expected.extend_from_slice(&message.deadline.to_be_bytes());
+    assert_eq!(message.encode_legacy(SignatureScheme::Ed25519, 9).unwrap(), expected);
+}
+
+#[test]
+fn canonical_amounts_scale_exactly() {
+    assert_eq!(canonical_amount(1_500_000, 6).unwrap(), 1_500_000_000_000_000_000);
+    let message = apply_message();
+    let mut dusty = message.clone();
+    dusty.amount += 1;
+    assert!(dusty.encode_legacy(SignatureScheme::Ed25519, 6).is_err());
+    assert!(message.encode_legacy(SignatureScheme::Ed25519, 6).is_ok());
+}
+
+#[test]
+fn pdas_match_program_seeds() {
+    let mint = Pubkey::new_from_array([13; 32]);
+    assert_eq!(
+        pda::vault(&mint),
+        Pubkey::find_program_address(&[b"vault", mint.as_ref()], &PROGRAM_ID)
+    );
+    assert_eq!(
+        pda::token_route(&mint, 56),
+        Pubkey::find_program_address(&[b"route", mint.as_ref(), &56u64.to_be_bytes()], &PROGRAM_ID)
+    );
+}
+
+#[test]
+fn global_account_decodes() {
+    let global = GlobalAccount {
+        admin: Pubkey::new_from_array([14; 32]),
+        pending_admin: Pubkey::default(),
+        token_fee_rate: 3_000_000,
+        treasury: Pubkey::new_from_array([15; 32]),
+        fee_mode: FeeMode::Deducted,
+        fee_rounding: FeeRounding::Down,
+        guardian: Pubkey::new_from_array([16; 32]),
+        applies_paused: false,
+        claims_paused: true,
+        chain_id: 101,
+        legacy_messages_until: 0,
+        emergency_withdrawal_delay: 172_800,
+    };
+    let mut data = Vec::new();
+    global.try_serialize(&mut data).unwrap();
+
+    let decoded = accounts::global_account(&data).unwrap();
+    assert_eq!(decoded.admin, global.admin);
+    assert_eq!(decoded.token_fee_rate, global.token_fee_rate);
+    assert_eq!(decoded.fee_mode, FeeMode::Deducted);
+    assert!(decoded.claims_paused);
+    assert_eq!(decoded.chain_id, 101);
+    assert_eq!(decoded.emergency_withdrawal_delay, 172_800);
+}
@@ -0,0 +1,160 @@
+use crate::Result;
+use bridge_client::messages::ClaimTokenMessage;
+use bridge_client::{ed25519, secp256k1, Domain, SignatureScheme};
+use serde::{Deserialize, Serialize};
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use std::convert::TryInto;
+
+/// An apply order as recorded on its source chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppliedOrder {
+    pub order_id: [u8; 32],
+    pub applicant: [u8; 32],
+    pub receipient: [u8; 32],
+    pub from_chain_id: u64,
+    pub from_token: [u8; 32],
+    /// Canonical amount, see `bridge_client::messages`.
+    pub amount: u128,
+    pub to_chain_id: u64,
+}
+
+/// A local token routed from a remote chain, see `TokenRoute`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoutedToken {
+    pub token: [u8; 32],
+    /// Decimals of the token on the remote chain.
+    pub remote_decimals: u8,
+}
+
+/// The instruction a claim is signed for on the destination chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaimKind {
+    /// `claim_token`, submitted by the recipient.
+    ClaimToken,
+    /// `claim_sol`, submitted by the recipient to receive lamports. It takes
+    /// the `claim_token` message for the native mint, so the same signature
+    /// also serves `claim_token`.
+    ClaimSol,
+    /// `deliver_token`, submitted by any relayer, who keeps `relayer_fee`
+    /// (canonical) out of the amount.
+    DeliverToken { relayer_fee: u128 },
+}
+
+impl ClaimKind {
+    /// Native SOL is always claimed as lamports; other tokens are delivered
+    /// when the destination pays relayers, claimed by the recipient otherwise.
+    pub fn for_token(to_token: &[u8; 32], relayer_fee: Option<u128>) -> Self {
+        if *to_token == spl_token::native_mint::id().to_bytes() {
+            return ClaimKind::ClaimSol;
+        }
+        match relayer_fee {
+            Some(relayer_fee) => ClaimKind::DeliverToken { relayer_fee },
+            None => ClaimKind::ClaimToken,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "SignatureScheme")]
+enum SignatureSchemeDef {
+    Ed25519,
+    Secp256k1,
+}
+
+/// A claim signed by this validator, ready to be submitted on `to_chain_id`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedClaim {
+    pub order_id: [u8; 32],
+    pub applicant: [u8; 32],
+    pub receipient: [u8; 32],
+    pub from_chain_id: u64,
+    pub to_chain_id: u64,
+    pub to_token: [u8; 32],
+    /// Canonical amount, see `bridge_client::messages`.
+    pub amount: u128,
+    pub deadline: i64,
+    pub kind: ClaimKind,
+    /// Scheme of the destination's validator set when the claim was signed.
+    #[serde(with = "SignatureSchemeDef")]
+    pub scheme: SignatureScheme,
+    /// The validator key as the validator set stores it: an ed25519 public
+    /// key, or a left-padded Ethereum address for `Secp256k1`.
+    pub validator: [u8; 32],
+    /// 64 bytes for `Ed25519`; for `Secp256k1`, followed by the recovery id.
+    pub signature: Vec<u8>,
+}
+
+impl SignedClaim {
+    /// The bytes signed by the validator, laid out as the claim instruction
+    /// expects.
+    pub fn message(&self, domain: &Domain) -> Vec<u8> {
+        let message = ClaimTokenMessage {
+            order_id: self.order_id,
+            applicant: self.applicant,
+            receipient: Pubkey::new_from_array(self.receipient),
+            from_chain_id: self.from_chain_id,
+            to_chain_id: self.to_chain_id,
+            to_token: Pubkey::new_from_array(self.to_token),
+            amount: self.amount,
+            deadline: self.deadline,
+        };
+        match self.kind {
+            ClaimKind::ClaimToken | ClaimKind::ClaimSol => message.encode(self.scheme, domain),
+            ClaimKind::DeliverToken { relayer_fee } => message.encode_delivery(self.scheme, domain, relayer_fee),
+        }
+    }
+
+    /// The Ed25519 or Secp256k1 instruction to place next to the claim
+    /// instruction, at `instruction_index` in the transaction.
+    pub fn verify_instruction(&self, domain: &Domain, instruction_index: u8) -> Result<Instruction> {
+        let message = self.message(domain);
+        Ok(match self.scheme {
+            SignatureScheme::Ed25519 => {
+                let signature = ed25519::ValidatorSignature {
+                    pubkey: self.validator,
+                    signature: self.signature.as_slice().try_into()?,
+                };
+                ed25519::new_verify_instruction(&[signature], &message)
+            }
+            SignatureScheme::Secp256k1 => {
+                let (recovery_id, signature) = self.signature.split_last().ok_or("empty signature")?;
+                let signature = secp256k1::ValidatorSignature {
+                    eth_address: self.validator[12..].try_into()?,
+                    signature: signature.try_into()?,
+                    recovery_id: *recovery_id,
+                };
+                secp256k1::new_verify_instruction(&[signature], &message, instruction_index)
+            }
+        })
+    }
+}
+
+/// Connection to one chain running the bridge.
+pub trait ChainAdapter {
+    fn chain_id(&self) -> u64;
+
+    /// Domain the bridge deployment on this chain binds its messages to.
+    fn domain(&self) -> Domain;
+
+    /// Scheme the validator set on this chain verifies claims with.
+    fn signature_scheme(&self) -> Result<SignatureScheme>;
+
+    /// Apply orders made on this chain. Orders may be returned again on later
+    /// calls, the signer ignores the ones it already handled.
+    fn poll_applied_orders(&mut self) -> Result<Vec<AppliedOrder>>;
+
+    /// The local token bridged from `from_token` on `from_chain_id`, if any.
+    fn resolve_token(&self, from_chain_id: u64, from_token: &[u8; 32]) -> Result<Option<RoutedToken>>;
+
+    /// Canonical fee offered to relayers; claims are signed for
+    /// `deliver_token` when set, see `ClaimKind::for_token`.
+    fn relayer_fee(&self) -> Option<u128>;
+
+    /// Whether the claim of `order_id` was executed on this chain.
+    fn is_claimed(&self, order_id: &[u8; 32]) -> Result<bool>;
+
+    /// Hands a signed claim over to whoever submits it on this chain.
+    fn publish_claim(&mut self, claim: &SignedClaim) -> Result<()>;
+}
@@ -0,0 +1,102 @@
+use crate::chain::ChainAdapter;
+use crate::solana::SolanaChain;
+use crate::Result;
+use serde::{de, Deserialize, Deserializer};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Service settings, read from the JSON file given on the command line.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Config {
+    /// JSON keypair file of the validator, as written by `solana-keygen`.
+    pub keypair: PathBuf,
+    /// JSON array of the 32 bytes of the validator's secp256k1 secret key,
+    /// needed for chains whose validator set uses `Secp256k1`.
+    #[serde(default)]
+    pub secp256k1_key: Option<PathBuf>,
+    /// Directory of the signature store.
+    pub db: PathBuf,
+    #[serde(default = "default_poll_seconds")]
+    pub poll_seconds: u64,
+    /// Seconds a signed claim stays valid.
+    #[serde(default = "default_claim_validity")]
+    pub claim_validity: i64,
+    pub chains: Vec<ChainConfig>,
+}
+
+/// A chain to watch for apply orders and to publish claims on.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChainConfig {
+    Solana {
+        rpc_url: String,
+        chain_id: u64,
+        /// File the signed claims for this chain are appended to.
+        outbox: PathBuf,
+        /// Canonical fee left to relayers, as a decimal string since JSON
+        /// numbers stop short of canonical amounts. Claims are signed for
+        /// `deliver_token` when set, for `claim_token` otherwise.
+        #[serde(default, deserialize_with = "deserialize_amount")]
+        relayer_fee: Option<u128>,
+    },
+}
+
+fn deserialize_amount<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Option<u128>, D::Error> {
+    match Option::<String>::deserialize(deserializer)? {
+        Some(amount) => amount.parse().map(Some).map_err(de::Error::custom),
+        None => Ok(None),
+    }
+}
+
+fn default_poll_seconds() -> u64 {
+    10
+}
+
+fn default_claim_validity() -> i64 {
+    24 * 60 * 60
+}
+
+impl ChainConfig {
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            ChainConfig::Solana { chain_id, .. } => *chain_id,
+        }
+    }
+}
+
+impl Config {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let config: Config = serde_json::from_str(&fs::read_to_string(path)?)?;
+        if config.chains.is_empty() {
+            return Err("no chains configured".into());
+        }
+        for (i, chain) in config.chains.iter().enumerate() {
+            if config.chains[..i].iter().any(|other| other.chain_id() == chain.chain_id()) {
+                return Err(format!("chain {} configured twice", chain.chain_id()).into());
+            }
+        }
+        Ok(config)
+    }
+
+    /// One adapter per configured chain, in configuration order.
+    pub fn adapters(&self) -> Vec<Box<dyn ChainAdapter>> {
+        self.chains
+            .iter()
+            .map(|chain| -> Box<dyn ChainAdapter> {
+                match chain {
+                    ChainConfig::Solana {
+                        rpc_url,
+                        chain_id,
+                        outbox,
+                        relayer_fee,
+                    } => Box::new(SolanaChain::new(
+                        rpc_url.clone(),
+                        *chain_id,
+                        outbox.clone(),
+                        *relayer_fee,
+                    )),
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,13 @@
+//! Watches apply orders on the chains it is connected to and signs the
+//! matching claim for the destination chain, at most once per order id.
+//!
+//! Chains are reached through `ChainAdapter`s; `mock::MockChain` keeps a whole
+//! chain in memory so the apply -> sign -> claim flow runs offline.
+pub mod chain;
+pub mod config;
+pub mod mock;
+pub mod signer;
+pub mod solana;
+pub mod store;
+
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
@@ -0,0 +1,57 @@
+use bridge_signer::config::Config;
+use bridge_signer::signer::{Decision, Signer};
+use bridge_signer::store::SignatureStore;
+use bridge_signer::Result;
+use ed25519_dalek::Keypair;
+use log::{error, info, warn};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, fs, thread};
+
+const USAGE: &str = "usage: bridge-signer <config-file>";
+
+fn read_keypair(path: &Path) -> Result<Keypair> {
+    let bytes: Vec<u8> = serde_json::from_str(&fs::read_to_string(path)?)?;
+    Ok(Keypair::from_bytes(&bytes)?)
+}
+
+fn read_secp256k1_key(path: &Path) -> Result<libsecp256k1::SecretKey> {
+    let bytes: [u8; 32] = serde_json::from_str(&fs::read_to_string(path)?)?;
+    Ok(libsecp256k1::SecretKey::parse(&bytes)?)
+}
+
+fn main() -> Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let config = Config::load(env::args().nth(1).ok_or(USAGE)?)?;
+    let keypair = read_keypair(&config.keypair)?;
+    let store = SignatureStore::open(&config.db)?;
+    let poll_interval = Duration::from_secs(config.poll_seconds);
+
+    let mut signer = Signer::new(keypair, store, config.claim_validity);
+    if let Some(path) = &config.secp256k1_key {
+        signer = signer.with_secp256k1_key(read_secp256k1_key(path)?);
+    }
+    let mut chains = config.adapters();
+    info!(
+        "validator {} watching chains {:?}",
+        bs58::encode(signer.validator()).into_string(),
+        config.chains.iter().map(|chain| chain.chain_id()).collect::<Vec<_>>()
+    );
+    loop {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        match signer.run_once(&mut chains, now) {
+            Ok(decisions) => {
+                for (order_id, decision) in decisions {
+                    match decision {
+                        Decision::Signed(_) => info!("signed claim for order {:?}", order_id),
+                        Decision::Renewed(_) => info!("renewed expired claim for order {:?}", order_id),
+                        Decision::Rejected(reason) => warn!("rejected order {:?}: {}", order_id, reason),
+                        Decision::AlreadySigned(_) => {}
+                    }
+                }
+            }
+            Err(err) => error!("poll failed: {}", err),
+        }
+        thread::sleep(poll_interval);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::chain::{AppliedOrder, ChainAdapter, ClaimKind, RoutedToken, SignedClaim};
+use crate::Result;
+use bridge_client::{secp256k1, Domain, SignatureScheme};
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+use solana_program::keccak;
+use solana_program::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct MockState {
+    applied: Vec<AppliedOrder>,
+    polled: usize,
+    routes: HashMap<(u64, [u8; 32]), RoutedToken>,
+    published: Vec<SignedClaim>,
+    claims: Vec<SignedClaim>,
+}
+
+/// An in-memory chain running the bridge. Clones share the same state, so a
+/// test keeps a handle while the signer owns another one.
+#[derive(Clone)]
+pub struct MockChain {
+    chain_id: u64,
+    program_id: Pubkey,
+    scheme: SignatureScheme,
+    validators: Vec<[u8; 32]>,
+    relayer_fee: Option<u128>,
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockChain {
+    /// A chain whose validator set holds the ed25519 `validators`.
+    pub fn new(chain_id: u64, validators: Vec<[u8; 32]>) -> Self {
+        MockChain {
+            chain_id,
+            program_id: bridge::ID,
+            scheme: SignatureScheme::Ed25519,
+            validators,
+            relayer_fee: None,
+            state: Arc::default(),
+        }
+    }
+
+    /// Switches the validator set to `scheme`, holding `validators` in the
+    /// form `ValidatorSet` stores them.
+    pub fn with_validators(mut self, scheme: SignatureScheme, validators: Vec<[u8; 32]>) -> Self {
+        self.scheme = scheme;
+        self.validators = validators;
+        self
+    }
+
+    pub fn with_relayer_fee(mut self, relayer_fee: u128) -> Self {
+        self.relayer_fee = Some(relayer_fee);
+        self
+    }
+
+    /// Records an apply order as `apply_token` would on this chain.
+    pub fn apply(&self, order: AppliedOrder) {
+        self.state.lock().unwrap().applied.push(order);
+    }
+
+    pub fn add_route(&self, from_chain_id: u64, from_token: [u8; 32], token: [u8; 32], remote_decimals: u8) {
+        self.state
+            .lock()
+            .unwrap()
+            .routes
+            .insert((from_chain_id, from_token), RoutedToken { token, remote_decimals });
+    }
+
+    /// Claims handed over by the signer, waiting to be submitted.
+    pub fn published(&self) -> Vec<SignedClaim> {
+        self.state.lock().unwrap().published.clone()
+    }
+
+    /// Claims executed on this chain, in order.
+    pub fn claims(&self) -> Vec<SignedClaim> {
+        self.state.lock().unwrap().claims.clone()
+    }
+
+    /// Executes a signed claim with the checks the claim instruction makes:
+    /// signature by a known validator over this chain's domain with its
+    /// scheme, matching chain and routed token, a relayer fee covered by the
+    /// amount, and an order id claimed at most once.
+    pub fn claim(&self, claim: &SignedClaim, now: i64) -> Result<()> {
+        if claim.to_chain_id != self.chain_id {
+            return Err("claim is for another chain".into());
+        }
+        if now > claim.deadline {
+            return Err("claim expired".into());
+        }
+        if claim.scheme != self.scheme || !self.validators.contains(&claim.validator) {
+            return Err("claim not signed by a validator".into());
+        }
+        if let ClaimKind::DeliverToken { relayer_fee } = claim.kind {
+            if relayer_fee > claim.amount {
+                return Err("relayer fee exceeds the amount".into());
+            }
+        }
+        self.verify_signature(claim)?;
+
+        let mut state = self.state.lock().unwrap();
+        if state.claims.iter().any(|existing| existing.order_id == claim.order_id) {
+            return Err("order already claimed".into());
+        }
+        if !state.routes.values().any(|route| route.token == claim.to_token) {
+            return Err("token is not bridged on this chain".into());
+        }
+        state.claims.push(claim.clone());
+        Ok(())
+    }
+
+    /// Checks the verify instruction as the bridge reads it, then the
+    /// signature as the Ed25519 or Secp256k1 program does.
+    fn verify_signature(&self, claim: &SignedClaim) -> Result<()> {
+        let message = claim.message(&self.domain());
+        let ix = claim.verify_instruction(&self.domain(), 0)?;
+        let signers = match self.scheme {
+            SignatureScheme::Ed25519 => bridge::utils::check_ed25519_data(&ix.data, &message),
+            SignatureScheme::Secp256k1 => bridge::utils::check_secp256k1_data(&ix.data, &message, 0),
+        }
+        .map_err(|err| format!("{:?}", err))?;
+        if signers != vec![claim.validator] {
+            return Err("verify instruction does not cover the claim".into());
+        }
+        match self.scheme {
+            SignatureScheme::Ed25519 => PublicKey::from_bytes(&claim.validator)?
+                .verify(&message, &Signature::try_from(claim.signature.as_slice())?)?,
+            SignatureScheme::Secp256k1 => {
+                let (recovery_id, signature) = claim.signature.split_last().ok_or("empty signature")?;
+                let pubkey = libsecp256k1::recover(
+                    &libsecp256k1::Message::parse(&keccak::hash(&message).to_bytes()),
+                    &libsecp256k1::Signature::parse_standard_slice(signature)?,
+                    &libsecp256k1::RecoveryId::parse(*recovery_id)?,
+                )?
+                .serialize();
+                if secp256k1::validator_key(&secp256k1::eth_address(pubkey[1..].try_into()?)) != claim.validator {
+                    return Err("signature does not recover to the validator".into());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ChainAdapter for MockChain {
+    fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    fn domain(&self) -> Domain {
+        Domain {
+            program_id: self.program_id,
+            chain_id: self.chain_id,
+        }
+    }
+
+    fn signature_scheme(&self) -> Result<SignatureScheme> {
+        Ok(self.scheme)
+    }
+
+    fn poll_applied_orders(&mut self) -> Result<Vec<AppliedOrder>> {
+        let mut state = self.state.lock().unwrap();
+        let orders = state.applied[state.polled..].to_vec();
+        state.polled = state.applied.len();
+        Ok(orders)
+    }
+
+    fn resolve_token(&self, from_chain_id: u64, from_token: &[u8; 32]) -> Result<Option<RoutedToken>> {
+        Ok(self.state.lock().unwrap().routes.get(&(from_chain_id, *from_token)).copied())
+    }
+
+    fn relayer_fee(&self) -> Option<u128> {
+        self.relayer_fee
+    }
+
+    fn is_claimed(&self, order_id: &[u8; 32]) -> Result<bool> {
+        Ok(self.state.lock().unwrap().claims.iter().any(|claim| claim.order_id == *order_id))
+    }
+
+    fn publish_claim(&mut self, claim: &SignedClaim) -> Result<()> {
+        self.state.lock().unwrap().published.push(claim.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+use crate::chain::{AppliedOrder, ChainAdapter, ClaimKind, SignedClaim};
+use crate::store::SignatureStore;
+use crate::Result;
+use bridge_client::messages::encode_amount;
+use bridge_client::{secp256k1, SignatureScheme};
+use ed25519_dalek::{Keypair, Signer as _};
+use solana_program::keccak;
+use std::convert::TryInto;
+
+/// What the signer did with an apply order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decision {
+    Signed(SignedClaim),
+    AlreadySigned(SignedClaim),
+    /// The stored claim expired unclaimed and was signed again with a new
+    /// deadline.
+    Renewed(SignedClaim),
+    Rejected(&'static str),
+}
+
+pub struct Signer {
+    keypair: Keypair,
+    /// Signs for chains whose validator set uses `SignatureScheme::Secp256k1`.
+    secp256k1_key: Option<libsecp256k1::SecretKey>,
+    store: SignatureStore,
+    /// Seconds a signed claim stays valid.
+    claim_validity: i64,
+}
+
+impl Signer {
+    pub fn new(keypair: Keypair, store: SignatureStore, claim_validity: i64) -> Self {
+        Signer {
+            keypair,
+            secp256k1_key: None,
+            store,
+            claim_validity,
+        }
+    }
+
+    pub fn with_secp256k1_key(mut self, key: libsecp256k1::SecretKey) -> Self {
+        self.secp256k1_key = Some(key);
+        self
+    }
+
+    pub fn validator(&self) -> [u8; 32] {
+        self.keypair.public.to_bytes()
+    }
+
+    /// The validator key for `scheme`, as the validator set stores it.
+    pub fn validator_key(&self, scheme: SignatureScheme) -> Option<[u8; 32]> {
+        match scheme {
+            SignatureScheme::Ed25519 => Some(self.validator()),
+            SignatureScheme::Secp256k1 => {
+                let pubkey = libsecp256k1::PublicKey::from_secret_key(self.secp256k1_key.as_ref()?).serialize();
+                Some(secp256k1::validator_key(&secp256k1::eth_address(
+                    pubkey[1..].try_into().unwrap(),
+                )))
+            }
+        }
+    }
+
+    /// The validator key for `scheme` and its signature over `message`, as the
+    /// Ed25519 or Secp256k1 program verifies it, if this validator holds a key
+    /// for `scheme`.
+    fn sign(&self, scheme: SignatureScheme, message: &[u8]) -> Option<([u8; 32], Vec<u8>)> {
+        let validator = self.validator_key(scheme)?;
+        let signature = match scheme {
+            SignatureScheme::Ed25519 => self.keypair.sign(message).to_bytes().to_vec(),
+            SignatureScheme::Secp256k1 => {
+                let hash = libsecp256k1::Message::parse(&keccak::hash(message).to_bytes());
+                let (signature, recovery_id) = libsecp256k1::sign(&hash, self.secp256k1_key.as_ref()?);
+                let mut signature = signature.serialize().to_vec();
+                signature.push(recovery_id.serialize());
+                signature
+            }
+        };
+        Some((validator, signature))
+    }
+
+    /// Signs the claim matching `order`, seen on `source_chain_id`, for
+    /// `destination`, with the scheme of the destination's validator set.
+    ///
+    /// An order id is signed once: while its stored claim is valid, or once it
+    /// was executed, the stored claim is returned as `AlreadySigned`. A claim
+    /// that expired unexecuted is signed again with a new deadline and
+    /// replaces the stored one. Refunds are signed outside this service, so an
+    /// order must only be refunded once no signer can renew its claim anymore.
+    pub fn decide(
+        &self,
+        order: &AppliedOrder,
+        source_chain_id: u64,
+        destination: &dyn ChainAdapter,
+        now: i64,
+    ) -> Result<Decision> {
+        let expired = match self.store.get(&order.order_id)? {
+            Some(claim) if now <= claim.deadline || destination.is_claimed(&claim.order_id)? => {
+                return Ok(Decision::AlreadySigned(claim));
+            }
+            expired => expired,
+        };
+        if order.from_chain_id != source_chain_id {
+            return Ok(Decision::Rejected("from_chain_id does not match the source chain"));
+        }
+        if order.to_chain_id != destination.chain_id() {
+            return Ok(Decision::Rejected("to_chain_id does not match the destination chain"));
+        }
+        if order.amount == 0 {
+            return Ok(Decision::Rejected("zero amount"));
+        }
+        let route = match destination.resolve_token(order.from_chain_id, &order.from_token)? {
+            Some(route) => route,
+            None => return Ok(Decision::Rejected("token is not routed to the destination chain")),
+        };
+        if bridge::utils::check_canonical_precision(&encode_amount(order.amount), route.remote_decimals).is_err() {
+            return Ok(Decision::Rejected("amount is finer than the remote token's decimals"));
+        }
+        let kind = ClaimKind::for_token(&route.token, destination.relayer_fee());
+        if let ClaimKind::DeliverToken { relayer_fee } = kind {
+            if relayer_fee > order.amount {
+                return Ok(Decision::Rejected("amount does not cover the relayer fee"));
+            }
+        }
+        let scheme = destination.signature_scheme()?;
+
+        let mut claim = SignedClaim {
+            order_id: order.order_id,
+            applicant: order.applicant,
+            receipient: order.receipient,
+            from_chain_id: order.from_chain_id,
+            to_chain_id: order.to_chain_id,
+            to_token: route.token,
+            amount: order.amount,
+            deadline: now + self.claim_validity,
+            kind,
+            scheme,
+            validator: [0; 32],
+            signature: vec![],
+        };
+        match self.sign(scheme, &claim.message(&destination.domain())) {
+            Some((validator, signature)) => {
+                claim.validator = validator;
+                claim.signature = signature;
+            }
+            None => return Ok(Decision::Rejected("no key for the destination's signature scheme")),
+        }
+        match expired {
+            None => match self.store.insert_once(&claim)? {
+                None => Ok(Decision::Signed(claim)),
+                Some(existing) => Ok(Decision::AlreadySigned(existing)),
+            },
+            Some(expired) => match self.store.renew(&expired, &claim)? {
+                None => Ok(Decision::Renewed(claim)),
+                Some(existing) => Ok(Decision::AlreadySigned(existing)),
+            },
+        }
+    }
+
+    /// Polls every chain once and publishes a claim on the destination chain
+    /// for each new apply order, including claims signed but not published
+    /// before a restart.
+    pub fn run_once(&self, chains: &mut [Box<dyn ChainAdapter>], now: i64) -> Result<Vec<([u8; 32], Decision)>> {
+        let mut decisions = Vec::new();
+        for source in 0..chains.len() {
+            let source_chain_id = chains[source].chain_id();
+            for order in chains[source].poll_applied_orders()? {
+                let destination = match chains.iter().position(|chain| chain.chain_id() == order.to_chain_id) {
+                    Some(destination) => destination,
+                    None => {
+                        decisions.push((order.order_id, Decision::Rejected("unknown destination chain")));
+                        continue;
+                    }
+                };
+                let decision = self.decide(&order, source_chain_id, chains[destination].as_ref(), now)?;
+                if let Decision::Signed(claim) | Decision::AlreadySigned(claim) | Decision::Renewed(claim) = &decision {
+                    if !self.store.is_published(&claim.order_id)? {
+                        chains[destination].publish_claim(claim)?;
+                        self.store.mark_published(&claim.order_id)?;
+                    }
+                }
+                decisions.push((order.order_id, decision));
+            }
+        }
+        Ok(decisions)
+    }
+}
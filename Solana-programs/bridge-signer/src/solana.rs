@@ -0,0 +1,174 @@
+use crate::chain::{AppliedOrder, ChainAdapter, RoutedToken, SignedClaim};
+use crate::Result;
+use bridge::program_accounts::{OrderAccount, OrderDirection, OrderStatus};
+use bridge_client::messages::canonical_amount;
+use bridge_client::{accounts, pda, Domain, SignatureScheme};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Offset of `OrderAccount::direction`, right after the discriminator and the order id.
+const ORDER_DIRECTION_OFFSET: usize = 8 + 32;
+
+/// The bridge program on a Solana cluster, reached over JSON-RPC.
+pub struct SolanaChain {
+    rpc: RpcClient,
+    chain_id: u64,
+    outbox: PathBuf,
+    relayer_fee: Option<u128>,
+    decimals: HashMap<Pubkey, u8>,
+}
+
+impl SolanaChain {
+    pub fn new(rpc_url: String, chain_id: u64, outbox: PathBuf, relayer_fee: Option<u128>) -> Self {
+        SolanaChain {
+            rpc: RpcClient::new(rpc_url),
+            chain_id,
+            outbox,
+            relayer_fee,
+            decimals: HashMap::new(),
+        }
+    }
+
+    fn mint_decimals(&mut self, mint: &Pubkey) -> Result<u8> {
+        if let Some(decimals) = self.decimals.get(mint) {
+            return Ok(*decimals);
+        }
+        let account = self.rpc.get_account(mint)?;
+        let decimals = spl_token::state::Mint::unpack(&account.data)?.decimals;
+        self.decimals.insert(*mint, decimals);
+        Ok(decimals)
+    }
+
+    /// Data of the bridge accounts of `len` bytes starting with
+    /// `discriminator`, narrowed down by the node with the extra `filters`.
+    fn program_accounts(&self, len: usize, discriminator: [u8; 8], filters: Vec<Memcmp>) -> Result<Vec<Vec<u8>>> {
+        let mut all_filters = vec![
+            RpcFilterType::DataSize(len as u64),
+            RpcFilterType::Memcmp(memcmp(0, &discriminator)),
+        ];
+        all_filters.extend(filters.into_iter().map(RpcFilterType::Memcmp));
+        let config = RpcProgramAccountsConfig {
+            filters: Some(all_filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            with_context: None,
+        };
+        Ok(self
+            .rpc
+            .get_program_accounts_with_config(&bridge::ID, config)?
+            .into_iter()
+            .map(|(_, account)| account.data)
+            .collect())
+    }
+}
+
+fn memcmp(offset: usize, bytes: &[u8]) -> Memcmp {
+    Memcmp {
+        offset,
+        bytes: MemcmpEncodedBytes::Base58(bs58::encode(bytes).into_string()),
+        encoding: None,
+    }
+}
+
+impl ChainAdapter for SolanaChain {
+    fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    fn domain(&self) -> Domain {
+        Domain {
+            program_id: bridge::ID,
+            chain_id: self.chain_id,
+        }
+    }
+
+    fn signature_scheme(&self) -> Result<SignatureScheme> {
+        let account = self.rpc.get_account(&pda::validator_set().0)?;
+        Ok(accounts::validator_set(&account.data)?.scheme)
+    }
+
+    /// Every apply order account still holding its funds; refunded orders
+    /// must never be claimed.
+    fn poll_applied_orders(&mut self) -> Result<Vec<AppliedOrder>> {
+        let mut orders = Vec::new();
+        let mut order_accounts = Vec::new();
+        for direction in [OrderDirection::Apply, OrderDirection::ApplySol] {
+            // `status` directly follows `direction`.
+            let filter = memcmp(ORDER_DIRECTION_OFFSET, &[direction as u8, OrderStatus::Completed as u8]);
+            order_accounts.extend(self.program_accounts(
+                OrderAccount::LEN,
+                accounts::discriminator::<OrderAccount>(),
+                vec![filter],
+            )?);
+        }
+        for data in order_accounts {
+            let order = match accounts::order(&data) {
+                Ok(order) => order,
+                Err(_) => continue,
+            };
+            orders.push(AppliedOrder {
+                order_id: order.order_id,
+                applicant: order.applicant,
+                receipient: order.receipient,
+                from_chain_id: order.from_chain_id,
+                from_token: order.token,
+                amount: canonical_amount(order.amount, self.mint_decimals(&order.mint)?)
+                    .map_err(|err| format!("{:?}", err))?,
+                to_chain_id: order.to_chain_id,
+            });
+        }
+        Ok(orders)
+    }
+
+    /// Looks the route up at `["route", mint, from_chain_id]` for every mint
+    /// in the token registry.
+    fn resolve_token(&self, from_chain_id: u64, from_token: &[u8; 32]) -> Result<Option<RoutedToken>> {
+        let registry = accounts::token_registry(&self.rpc.get_account(&pda::token_registry().0)?.data)?;
+        let routes: Vec<Pubkey> = registry
+            .tokens
+            .iter()
+            .map(|mint| pda::token_route(mint, from_chain_id).0)
+            .collect();
+        for account in self.rpc.get_multiple_accounts(&routes)?.into_iter().flatten() {
+            if let Ok(route) = accounts::token_route(&account.data) {
+                if route.enabled && route.remote_token == *from_token {
+                    return Ok(Some(RoutedToken {
+                        token: route.mint.to_bytes(),
+                        remote_decimals: route.remote_decimals,
+                    }));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    fn relayer_fee(&self) -> Option<u128> {
+        self.relayer_fee
+    }
+
+    fn is_claimed(&self, order_id: &[u8; 32]) -> Result<bool> {
+        let order = pda::claim_order(order_id).0;
+        Ok(self.rpc.get_account_with_commitment(&order, self.rpc.commitment())?.value.is_some())
+    }
+
+    /// Appends the claim as a JSON line to the outbox. `claim_token` and
+    /// `claim_sol` must be signed by the recipient, so the validator does not
+    /// submit claims itself: the recipient or a relayer picks them up from the
+    /// outbox.
+    fn publish_claim(&mut self, claim: &SignedClaim) -> Result<()> {
+        let mut outbox = OpenOptions::new().create(true).append(true).open(&self.outbox)?;
+        writeln!(outbox, "{}", serde_json::to_string(claim)?)?;
+        log::info!("published claim for order {:?} to {}", claim.order_id, self.outbox.display());
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use crate::chain::SignedClaim;
+use crate::Result;
+use std::path::Path;
+
+/// Local record of every claim signed, keyed by order id. A claim is written
+/// before it is published, so a restarted signer never signs an order twice
+/// while its claim is still valid.
+pub struct SignatureStore {
+    claims: sled::Tree,
+    published: sled::Tree,
+}
+
+impl SignatureStore {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_db(sled::open(path)?)
+    }
+
+    /// A store deleted when dropped, for tests.
+    pub fn temporary() -> Result<Self> {
+        Self::from_db(sled::Config::new().temporary(true).open()?)
+    }
+
+    fn from_db(db: sled::Db) -> Result<Self> {
+        Ok(SignatureStore {
+            claims: db.open_tree("claims")?,
+            published: db.open_tree("published")?,
+        })
+    }
+
+    pub fn get(&self, order_id: &[u8; 32]) -> Result<Option<SignedClaim>> {
+        match self.claims.get(order_id)? {
+            Some(value) => Ok(Some(serde_json::from_slice(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Stores `claim` unless one is already stored for its order id, in which
+    /// case the stored claim is returned and `claim` must be discarded.
+    pub fn insert_once(&self, claim: &SignedClaim) -> Result<Option<SignedClaim>> {
+        let value = serde_json::to_vec(claim)?;
+        match self.claims.compare_and_swap(claim.order_id, None::<&[u8]>, Some(value))? {
+            Ok(()) => {
+                self.claims.flush()?;
+                Ok(None)
+            }
+            Err(existing) => match existing.current {
+                Some(current) => Ok(Some(serde_json::from_slice(&current)?)),
+                None => Err("claim store changed concurrently".into()),
+            },
+        }
+    }
+
+    /// Replaces the `expired` claim stored for an order with `claim`, signed
+    /// again with a later deadline, and marks the order unpublished. If the
+    /// stored claim is no longer `expired`, it is returned instead.
+    pub fn renew(&self, expired: &SignedClaim, claim: &SignedClaim) -> Result<Option<SignedClaim>> {
+        let old = serde_json::to_vec(expired)?;
+        let value = serde_json::to_vec(claim)?;
+        match self.claims.compare_and_swap(claim.order_id, Some(old), Some(value))? {
+            Ok(()) => {
+                self.claims.flush()?;
+                self.published.remove(claim.order_id)?;
+                self.published.flush()?;
+                Ok(None)
+            }
+            Err(existing) => match existing.current {
+                Some(current) => Ok(Some(serde_json::from_slice(&current)?)),
+                None => Err("claim store changed concurrently".into()),
+            },
+        }
+    }
+
+    pub fn is_published(&self, order_id: &[u8; 32]) -> Result<bool> {
+        Ok(self.published.contains_key(order_id)?)
+    }
+
+    pub fn mark_published(&self, order_id: &[u8; 32]) -> Result<()> {
+        self.published.insert(order_id, Vec::new())?;
+        self.published.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use bridge_client::SignatureScheme;
+use bridge_signer::chain::{ClaimKind, SignedClaim};
+use bridge_signer::config::{ChainConfig, Config};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("bridge-signer-{}-{}", name, std::process::id()))
+}
+
+fn write_config(name: &str, chains: &str) -> PathBuf {
+    let path = temp_path(name);
+    let config = format!(
+        r#"{{"keypair": "validator.json", "db": "signatures", "poll_seconds": 5, "chains": {}}}"#,
+        chains
+    );
+    fs::write(&path, config).unwrap();
+    path
+}
+
+fn claim() -> SignedClaim {
+    SignedClaim {
+        order_id: [9; 32],
+        applicant: [3; 32],
+        receipient: [4; 32],
+        from_chain_id: 56,
+        to_chain_id: 101,
+        to_token: [2; 32],
+        amount: 1_000_000_000_000_000_000,
+        deadline: 1_700_000_600,
+        kind: ClaimKind::DeliverToken {
+            relayer_fee: 1_000_000_000_000_000_000_000,
+        },
+        scheme: SignatureScheme::Ed25519,
+        validator: [7; 32],
+        signature: vec![8; 64],
+    }
+}
+
+#[test]
+fn chains_are_registered_from_config() {
+    let outbox = temp_path("outbox");
+    let path = write_config(
+        "config",
+        &format!(
+            r#"[{{"kind": "solana", "rpc_url": "http://localhost:8899", "chain_id": 101, "outbox": {:?}, "relayer_fee": "1000000000000000000000"}}]"#,
+            outbox
+        ),
+    );
+    let config = Config::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(config.poll_seconds, 5);
+    assert_eq!(config.claim_validity, 24 * 60 * 60);
+    assert_eq!(
+        config.chains,
+        vec![ChainConfig::Solana {
+            rpc_url: "http://localhost:8899".to_string(),
+            chain_id: 101,
+            outbox: outbox.clone(),
+            relayer_fee: Some(1_000_000_000_000_000_000_000),
+        }]
+    );
+
+    let mut chains = config.adapters();
+    assert_eq!(chains.len(), 1);
+    assert_eq!(chains[0].chain_id(), 101);
+
+    // Publishing only touches the outbox, so no cluster is needed.
+    chains[0].publish_claim(&claim()).unwrap();
+    chains[0].publish_claim(&claim()).unwrap();
+    let published: Vec<SignedClaim> = fs::read_to_string(&outbox)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    fs::remove_file(&outbox).unwrap();
+    assert_eq!(published, vec![claim(), claim()]);
+}
+
+#[test]
+fn invalid_chain_lists_are_rejected() {
+    let path = write_config("empty", "[]");
+    assert!(Config::load(&path).is_err());
+    fs::remove_file(&path).unwrap();
+
+    let chain = r#"{"kind": "solana", "rpc_url": "http://localhost:8899", "chain_id": 101, "outbox": "claims"}"#;
+    let path = write_config("duplicate", &format!("[{}, {}]", chain, chain));
+    assert!(Config::load(&path).is_err());
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,231 @@
+use bridge_client::{secp256k1, SignatureScheme};
+use bridge_signer::chain::{AppliedOrder, ChainAdapter, ClaimKind};
+use bridge_signer::mock::MockChain;
+use bridge_signer::signer::{Decision, Signer};
+use bridge_signer::store::SignatureStore;
+use ed25519_dalek::{Keypair, PublicKey, SecretKey};
+use std::convert::TryInto;
+use std::env;
+use std::fs;
+
+const SOURCE: u64 = 101;
+const DESTINATION: u64 = 56;
+const NOW: i64 = 1_700_000_000;
+
+fn keypair() -> Keypair {
+    let secret = SecretKey::from_bytes(&[7; 32]).unwrap();
+    let public = PublicKey::from(&secret);
+    Keypair { secret, public }
+}
+
+fn chains() -> (MockChain, MockChain) {
+    let validators = vec![keypair().public.to_bytes()];
+    let source = MockChain::new(SOURCE, validators.clone());
+    let destination = MockChain::new(DESTINATION, validators);
+    destination.add_route(SOURCE, [1; 32], [2; 32], 18);
+    (source, destination)
+}
+
+fn order(order_id: u8, from_token: [u8; 32]) -> AppliedOrder {
+    AppliedOrder {
+        order_id: [order_id; 32],
+        applicant: [3; 32],
+        receipient: [4; 32],
+        from_chain_id: SOURCE,
+        from_token,
+        amount: 1_000_000_000_000_000_000,
+        to_chain_id: DESTINATION,
+    }
+}
+
+fn secp256k1_key() -> libsecp256k1::SecretKey {
+    libsecp256k1::SecretKey::parse(&[5; 32]).unwrap()
+}
+
+fn adapters(source: &MockChain, destination: &MockChain) -> Vec<Box<dyn ChainAdapter>> {
+    vec![Box::new(source.clone()), Box::new(destination.clone())]
+}
+
+#[test]
+fn apply_sign_claim() {
+    let (source, destination) = chains();
+    let signer = Signer::new(keypair(), SignatureStore::temporary().unwrap(), 600);
+    let mut chains = adapters(&source, &destination);
+
+    source.apply(order(9, [1; 32]));
+    let decisions = signer.run_once(&mut chains, NOW).unwrap();
+    assert!(matches!(decisions.as_slice(), [(_, Decision::Signed(_))]));
+
+    let published = destination.published();
+    assert_eq!(published.len(), 1);
+    let claim = &published[0];
+    assert_eq!(claim.to_token, [2; 32]);
+    assert_eq!(claim.deadline, NOW + 600);
+    assert_eq!((claim.kind, claim.scheme), (ClaimKind::ClaimToken, SignatureScheme::Ed25519));
+    destination.claim(claim, NOW + 10).unwrap();
+    assert_eq!(destination.claims(), published);
+
+    assert!(destination.claim(claim, NOW + 20).is_err());
+    assert!(signer.run_once(&mut chains, NOW + 30).unwrap().is_empty());
+}
+
+#[test]
+fn tampered_or_expired_claims_fail() {
+    let (source, destination) = chains();
+    let signer = Signer::new(keypair(), SignatureStore::temporary().unwrap(), 600);
+    source.apply(order(9, [1; 32]));
+    signer.run_once(&mut adapters(&source, &destination), NOW).unwrap();
+    let claim = destination.published().remove(0);
+
+    let mut tampered = claim.clone();
+    tampered.amount += 1;
+    assert!(destination.claim(&tampered, NOW).is_err());
+    assert!(destination.claim(&claim, NOW + 601).is_err());
+
+    let other_chain = MockChain::new(SOURCE, vec![keypair().public.to_bytes()]);
+    other_chain.add_route(SOURCE, [1; 32], [2; 32], 18);
+    assert!(other_chain.claim(&claim, NOW).is_err());
+}
+
+#[test]
+fn unrouted_orders_are_rejected() {
+    let (source, destination) = chains();
+    let signer = Signer::new(keypair(), SignatureStore::temporary().unwrap(), 600);
+    source.apply(order(9, [5; 32]));
+    let mut unknown = order(10, [1; 32]);
+    unknown.to_chain_id = 1;
+    source.apply(unknown);
+
+    let decisions = signer.run_once(&mut adapters(&source, &destination), NOW).unwrap();
+    assert!(matches!(decisions[0].1, Decision::Rejected(_)));
+    assert!(matches!(decisions[1].1, Decision::Rejected(_)));
+    assert!(destination.published().is_empty());
+}
+
+#[test]
+fn restarted_signer_never_signs_twice() {
+    let path = env::temp_dir().join(format!("bridge-signer-restart-{}", std::process::id()));
+    let (source, destination) = chains();
+    source.apply(order(9, [1; 32]));
+
+    let signer = Signer::new(keypair(), SignatureStore::open(&path).unwrap(), 600);
+    signer.run_once(&mut adapters(&source, &destination), NOW).unwrap();
+    drop(signer);
+    let first = destination.published();
+
+    // The source chain reports the same order again after the restart.
+    source.apply(order(9, [1; 32]));
+    let signer = Signer::new(keypair(), SignatureStore::open(&path).unwrap(), 600);
+    let decisions = signer.run_once(&mut adapters(&source, &destination), NOW + 100).unwrap();
+    assert_eq!(decisions, vec![([9; 32], Decision::AlreadySigned(first[0].clone()))]);
+    assert_eq!(destination.published(), first);
+    drop(signer);
+    fs::remove_dir_all(&path).unwrap();
+}
+
+#[test]
+fn secp256k1_destinations_get_secp256k1_claims() {
+    let key = secp256k1_key();
+    let pubkey = libsecp256k1::PublicKey::from_secret_key(&key).serialize();
+    let validator = secp256k1::validator_key(&secp256k1::eth_address(pubkey[1..].try_into().unwrap()));
+    let source = MockChain::new(SOURCE, vec![keypair().public.to_bytes()]);
+    let destination = MockChain::new(DESTINATION, vec![]).with_validators(SignatureScheme::Secp256k1, vec![validator]);
+    destination.add_route(SOURCE, [1; 32], [2; 32], 18);
+
+    // Without a secp256k1 key the validator cannot sign for this chain.
+    let signer = Signer::new(keypair(), SignatureStore::temporary().unwrap(), 600);
+    source.apply(order(9, [1; 32]));
+    let decisions = signer.run_once(&mut adapters(&source, &destination), NOW).unwrap();
+    assert!(matches!(decisions[0].1, Decision::Rejected(_)));
+
+    let signer = Signer::new(keypair(), SignatureStore::temporary().unwrap(), 600).with_secp256k1_key(key);
+    source.apply(order(9, [1; 32]));
+    signer.run_once(&mut adapters(&source, &destination), NOW).unwrap();
+    let claim = destination.published().remove(0);
+    assert_eq!(claim.scheme, SignatureScheme::Secp256k1);
+    assert_eq!((claim.validator, claim.signature.len()), (validator, 65));
+    destination.claim(&claim, NOW).unwrap();
+
+    // An ed25519 signature over the same claim is refused by this chain.
+    let mut ed25519_claim = claim.clone();
+    ed25519_claim.scheme = SignatureScheme::Ed25519;
+    assert!(destination.claim(&ed25519_claim, NOW).is_err());
+}
+
+#[test]
+fn claims_are_signed_for_the_destination_instruction() {
+    let native_mint = spl_token::native_mint::id().to_bytes();
+    let source = MockChain::new(SOURCE, vec![keypair().public.to_bytes()]);
+    let destination = MockChain::new(DESTINATION, vec![keypair().public.to_bytes()]).with_relayer_fee(1_000);
+    destination.add_route(SOURCE, [1; 32], [2; 32], 18);
+    destination.add_route(SOURCE, [6; 32], native_mint, 18);
+    let signer = Signer::new(keypair(), SignatureStore::temporary().unwrap(), 600);
+
+    source.apply(order(9, [1; 32]));
+    source.apply(order(10, [6; 32]));
+    let mut small = order(11, [1; 32]);
+    small.amount = 999;
+    source.apply(small);
+    let decisions = signer.run_once(&mut adapters(&source, &destination), NOW).unwrap();
+    assert!(matches!(decisions[2].1, Decision::Rejected(_)));
+
+    let published = destination.published();
+    assert_eq!(published.len(), 2);
+    assert_eq!(published[0].kind, ClaimKind::DeliverToken { relayer_fee: 1_000 });
+    assert_eq!(published[1].kind, ClaimKind::ClaimSol);
+    // A delivery signature is not a claim signature.
+    let mut as_claim = published[0].clone();
+    as_claim.kind = ClaimKind::ClaimToken;
+    assert!(destination.claim(&as_claim, NOW).is_err());
+    for claim in &published {
+        destination.claim(claim, NOW).unwrap();
+    }
+}
+
+#[test]
+fn amounts_finer_than_the_remote_decimals_are_rejected() {
+    let (source, destination) = chains();
+    destination.add_route(SOURCE, [6; 32], [7; 32], 6);
+    let signer = Signer::new(keypair(), SignatureStore::temporary().unwrap(), 600);
+    let mut dusty = order(9, [6; 32]);
+    dusty.amount += 1;
+    source.apply(dusty);
+    source.apply(order(10, [6; 32]));
+
+    let decisions = signer.run_once(&mut adapters(&source, &destination), NOW).unwrap();
+    assert!(matches!(decisions[0].1, Decision::Rejected(_)));
+    assert!(matches!(decisions[1].1, Decision::Signed(_)));
+}
+
+#[test]
+fn expired_claims_are_renewed_until_executed() {
+    let (source, destination) = chains();
+    let signer = Signer::new(keypair(), SignatureStore::temporary().unwrap(), 600);
+    let mut chains = adapters(&source, &destination);
+    source.apply(order(9, [1; 32]));
+    signer.run_once(&mut chains, NOW).unwrap();
+    let first = destination.published().remove(0);
+
+    // Still valid: the stored claim is kept.
+    source.apply(order(9, [1; 32]));
+    let decisions = signer.run_once(&mut chains, NOW + 600).unwrap();
+    assert_eq!(decisions, vec![([9; 32], Decision::AlreadySigned(first.clone()))]);
+
+    // Expired unclaimed: signed again under the same order id and republished.
+    source.apply(order(9, [1; 32]));
+    let decisions = signer.run_once(&mut chains, NOW + 601).unwrap();
+    let renewed = match &decisions[..] {
+        [(_, Decision::Renewed(renewed))] => renewed.clone(),
+        other => panic!("unexpected decisions {:?}", other),
+    };
+    assert_eq!((renewed.order_id, renewed.deadline), (first.order_id, NOW + 1_201));
+    assert_eq!(destination.published(), vec![first.clone(), renewed.clone()]);
+    assert!(destination.claim(&first, NOW + 601).is_err());
+    destination.claim(&renewed, NOW + 601).unwrap();
+
+    // Executed: never renewed again.
+    source.apply(order(9, [1; 32]));
+    let decisions = signer.run_once(&mut chains, NOW + 10_000).unwrap();
+    assert_eq!(decisions, vec![([9; 32], Decision::AlreadySigned(renewed))]);
+    assert_eq!(destination.published().len(), 2);
+}
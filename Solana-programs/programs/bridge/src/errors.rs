@@ -16,4 +16,121 @@ pub enum BridgeErrors {
 
     #[msg("Error: Invalid Vault PDA")]
     InvalidVaultPDA,
+
+    #[msg("Invalid Signer.")]
+    InvalidSigner,
+
+    #[msg("Not enough validator signatures.")]
+    InsufficientSignatures,
+
+    #[msg("Validator already exists.")]
+    ValidatorAlreadyExists,
+
+    #[msg("Validator not found.")]
+    ValidatorNotFound,
+
+    #[msg("Too many validators.")]
+    TooManyValidators,
+
+    #[msg("Invalid threshold.")]
+    InvalidThreshold,
+
+    #[msg("Insufficient vault liquidity.")]
+    InsufficientLiquidity,
+
+    #[msg("No fees to withdraw.")]
+    NoFeesToWithdraw,
+
+    #[msg("Invalid treasury token account.")]
+    InvalidTreasury,
+
+    #[msg("Math overflow.")]
+    MathOverflow,
+
+    #[msg("Invalid fee rate.")]
+    InvalidFeeRate,
+
+    #[msg("Invalid mint authority.")]
+    InvalidMintAuthority,
+
+    #[msg("Bridge is paused.")]
+    BridgePaused,
+
+    #[msg("Claim amount exceeds the per-claim limit.")]
+    ClaimAmountExceedsLimit,
+
+    #[msg("Claim exceeds the outflow rate limit.")]
+    RateLimitExceeded,
+
+    #[msg("Invalid rate limit.")]
+    InvalidRateLimit,
+
+    #[msg("Unsupported or disabled route.")]
+    UnsupportedRoute,
+
+    #[msg("to_token does not match the vault mint.")]
+    InvalidToToken,
+
+    #[msg("Relayer fee exceeds the claimed amount.")]
+    InvalidRelayerFee,
+
+    #[msg("Order already refunded.")]
+    OrderAlreadyRefunded,
+
+    #[msg("Fee already withdrawn, it cannot be refunded.")]
+    FeeNotRefundable,
+
+    #[msg("from_token does not match the user token account mint.")]
+    InvalidFromToken,
+
+    #[msg("from_chain_id does not match the local chain id.")]
+    InvalidFromChainId,
+
+    #[msg("to_chain_id does not match the local chain id.")]
+    InvalidToChainId,
+
+    #[msg("Token account is not owned by the signed recipient.")]
+    InvalidReceipientTokenAccount,
+
+    #[msg("Amount has more precision than the token supports.")]
+    AmountNotExact,
+
+    #[msg("Mint is not an NFT.")]
+    InvalidNft,
+
+    #[msg("NFT collection is not supported.")]
+    UnsupportedNft,
+
+    #[msg("Token is not registered or has been disabled.")]
+    UnsupportedToken,
+
+    #[msg("Amount is below the token minimum.")]
+    AmountBelowMinimum,
+
+    #[msg("Amount is above the token maximum.")]
+    AmountAboveMaximum,
+
+    #[msg("Invalid token amount limits.")]
+    InvalidAmountLimits,
+
+    #[msg("Token registry is full.")]
+    TooManyTokens,
+
+    #[msg("Emergency withdrawal delay is below the minimum.")]
+    InvalidWithdrawalDelay,
+
+    #[msg("Emergency withdrawal is not executable yet.")]
+    WithdrawalNotReady,
+
+    #[msg("Destination does not match the scheduled withdrawal.")]
+    InvalidWithdrawalDestination,
+
+    #[msg("Call target is not an executable program.")]
+    InvalidCallTarget,
+
+    #[msg("Order does not belong to this instruction.")]
+    InvalidOrderDirection,
+
+    #[msg("Vault must be empty to change the token mode.")]
+    VaultNotEmpty,
 }
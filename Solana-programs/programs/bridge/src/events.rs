@@ -0,0 +1,241 @@
+use crate::program_accounts::{FeeMode, FeeRounding, SignatureScheme, TokenMode};
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct TokenApplied {
+    pub order_id: [u8; 32],
+    pub applicant: [u8; 32],
+    pub receipient: [u8; 32],
+    pub from_chain_id: u64,
+    pub to_chain_id: u64,
+    pub token: Pubkey,
+    /// Amount taken from the applicant, fee included.
+    pub gross_amount: u64,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+/// For relayer-delivered claims `fee` is the relayer fee, already deducted
+/// from what the recipient received.
+#[event]
+pub struct TokenClaimed {
+    pub order_id: [u8; 32],
+    pub applicant: [u8; 32],
+    pub receipient: [u8; 32],
+    pub to_chain_id: u64,
+    pub token: Pubkey,
+    pub gross_amount: u64,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenRefunded {
+    pub order_id: [u8; 32],
+    pub applicant: [u8; 32],
+    pub token: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct ValidatorAdded {
+    pub validator: [u8; 32],
+}
+
+#[event]
+pub struct ValidatorRemoved {
+    pub validator: [u8; 32],
+}
+
+#[event]
+pub struct ThresholdUpdated {
+    pub threshold: u8,
+}
+
+#[event]
+pub struct SignatureSchemeUpdated {
+    pub scheme: SignatureScheme,
+    pub threshold: u8,
+    pub validators: Vec<[u8; 32]>,
+}
+
+#[event]
+pub struct TokenFeeRateUpdated {
+    pub token_fee_rate: u64,
+}
+
+#[event]
+pub struct FeeConfigUpdated {
+    pub fee_mode: FeeMode,
+    pub fee_rounding: FeeRounding,
+}
+
+#[event]
+pub struct MessageConfigUpdated {
+    pub chain_id: u64,
+    pub legacy_messages_until: i64,
+}
+
+#[event]
+pub struct TreasuryUpdated {
+    pub treasury: Pubkey,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub token: Pubkey,
+    pub treasury_token_account: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct GuardianUpdated {
+    pub guardian: Pubkey,
+}
+
+/// `token` is `None` when the whole bridge is paused.
+#[event]
+pub struct BridgePaused {
+    pub token: Option<Pubkey>,
+    pub applies: bool,
+    pub claims: bool,
+}
+
+#[event]
+pub struct BridgeUnpaused {
+    pub token: Option<Pubkey>,
+    pub applies: bool,
+    pub claims: bool,
+}
+
+#[event]
+pub struct RateLimitUpdated {
+    pub token: Pubkey,
+    pub max_claim_amount: u64,
+    pub window_limit: u64,
+    pub window_duration: i64,
+}
+
+#[event]
+pub struct ChainUpdated {
+    pub chain_id: u64,
+    pub enabled: bool,
+    pub fee_rate_override: Option<u64>,
+}
+
+#[event]
+pub struct RouteUpdated {
+    pub token: Pubkey,
+    pub chain_id: u64,
+    pub remote_token: [u8; 32],
+    pub remote_decimals: u8,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct TokenModeUpdated {
+    pub token: Pubkey,
+    pub mode: TokenMode,
+}
+
+#[event]
+pub struct TokenUpdated {
+    pub token: Pubkey,
+    pub enabled: bool,
+    pub fee_rate_override: Option<u64>,
+    pub min_amount: u64,
+    pub max_amount: u64,
+}
+
+#[event]
+pub struct EmergencyWithdrawalScheduled {
+    pub token: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct EmergencyWithdrawalExecuted {
+    pub token: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EmergencyWithdrawalCancelled {
+    pub token: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct EmergencyWithdrawalDelayUpdated {
+    pub delay: i64,
+}
+
+#[event]
+pub struct CallTargetUpdated {
+    pub program_id: Pubkey,
+    pub allowed: bool,
+}
+
+#[event]
+pub struct TokenClaimedAndCalled {
+    pub order_id: [u8; 32],
+    pub target_program: Pubkey,
+    pub payload_hash: [u8; 32],
+    /// Part of the claimed amount the target left behind, returned to the recipient.
+    pub returned_amount: u64,
+}
+
+#[event]
+pub struct NftApplied {
+    pub order_id: [u8; 32],
+    pub applicant: [u8; 32],
+    pub receipient: [u8; 32],
+    pub from_chain_id: u64,
+    pub to_chain_id: u64,
+    pub collection: Pubkey,
+    pub mint: Pubkey,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NftClaimed {
+    pub order_id: [u8; 32],
+    pub applicant: [u8; 32],
+    pub receipient: [u8; 32],
+    pub to_chain_id: u64,
+    pub collection: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NftFeeUpdated {
+    pub fee_currency: Pubkey,
+    pub fee: u64,
+}
+
+#[event]
+pub struct SupportedCollectionUpdated {
+    pub collection: Pubkey,
+    pub supported: bool,
+}
@@ -1,16 +1,34 @@
 use anchor_lang::prelude::*;
 pub(crate) mod prelude {
     pub use super::errors::BridgeErrors;
+    pub use super::events::*;
     pub use super::program_accounts::*;
     pub use super::program_instructions::*;
 }
 
-mod errors;
-mod program_accounts;
+pub mod errors;
+mod events;
+pub mod messages;
+mod metadata;
+pub mod program_accounts;
 mod program_instructions;
-mod utils;
+#[cfg(test)]
+mod test_utils;
+pub mod utils;
+use program_accounts::{FeeMode, FeeRounding, SignatureScheme, TokenMode};
 use program_instructions::{
-    apply_token::*, claim_token::*, initialize::*, initialize_user_account::*,
+    accept_admin::*, add_call_target::*, add_supported_collection::*, add_validator::*,
+    apply_nft::*, apply_sol::*, apply_token::*, cancel_emergency_withdrawal::*, claim_nft::*,
+    claim_sol::*, claim_token::*, claim_token_and_call::*, deliver_token::*, disable_token::*,
+    execute_emergency_withdrawal::*, initialize::*, initialize_nft_config::*, pause::*,
+    pause_token::*, refund_apply::*, refund_sol_apply::*, register_chain::*, register_route::*,
+    register_token::*, remove_call_target::*, remove_supported_collection::*, remove_validator::*,
+    schedule_emergency_withdrawal::*, set_token_mode::*, transfer_admin::*, unpause::*,
+    unpause_token::*, update_chain::*, update_emergency_withdrawal_delay::*, update_fee_config::*,
+    update_guardian::*, update_message_config::*, update_nft_fee::*, update_nft_fee_currency::*,
+    update_rate_limit::*, update_route::*, update_signature_scheme::*, update_signer::*,
+    update_threshold::*, update_token::*, update_token_fee_rate::*, update_treasury::*,
+    withdraw_fees::*,
 };
 
 declare_id!("AaYvypao2X3E44EzkuytSR1MbD3cF2UTRn4nNiYoZQLE");
@@ -22,12 +40,176 @@ pub mod bridge {
         ctx: Context<Initialize>,
         signer: [u8; 32],
         token_fee_rate: u64,
+        chain_id: u64,
     ) -> Result<()> {
-        Initialize::execute(ctx, signer, token_fee_rate)
+        Initialize::execute(ctx, signer, token_fee_rate, chain_id)
     }
 
-    pub fn initialize_user_account(ctx: Context<InitializeUserAccount>) -> Result<()> {
-        InitializeUserAccount::execute(ctx)
+    pub fn add_validator(ctx: Context<AddValidator>, validator: [u8; 32]) -> Result<()> {
+        AddValidator::execute(ctx, validator)
+    }
+
+    pub fn remove_validator(ctx: Context<RemoveValidator>, validator: [u8; 32]) -> Result<()> {
+        RemoveValidator::execute(ctx, validator)
+    }
+
+    pub fn update_threshold(ctx: Context<UpdateThreshold>, threshold: u8) -> Result<()> {
+        UpdateThreshold::execute(ctx, threshold)
+    }
+
+    /// Deprecated, see `UpdateSigner::execute`.
+    pub fn update_signer(ctx: Context<UpdateSigner>, signer: [u8; 32]) -> Result<()> {
+        UpdateSigner::execute(ctx, signer)
+    }
+
+    pub fn update_signature_scheme(
+        ctx: Context<UpdateSignatureScheme>,
+        scheme: SignatureScheme,
+        validators: Vec<[u8; 32]>,
+        threshold: u8,
+    ) -> Result<()> {
+        UpdateSignatureScheme::execute(ctx, scheme, validators, threshold)
+    }
+
+    pub fn update_token_fee_rate(ctx: Context<UpdateTokenFeeRate>, token_fee_rate: u64) -> Result<()> {
+        UpdateTokenFeeRate::execute(ctx, token_fee_rate)
+    }
+
+    pub fn update_message_config(
+        ctx: Context<UpdateMessageConfig>,
+        chain_id: u64,
+        legacy_messages_until: i64,
+    ) -> Result<()> {
+        UpdateMessageConfig::execute(ctx, chain_id, legacy_messages_until)
+    }
+
+    pub fn update_fee_config(
+        ctx: Context<UpdateFeeConfig>,
+        fee_mode: FeeMode,
+        fee_rounding: FeeRounding,
+    ) -> Result<()> {
+        UpdateFeeConfig::execute(ctx, fee_mode, fee_rounding)
+    }
+
+    pub fn update_treasury(ctx: Context<UpdateTreasury>, treasury: Pubkey) -> Result<()> {
+        UpdateTreasury::execute(ctx, treasury)
+    }
+
+    pub fn register_token(
+        ctx: Context<RegisterToken>,
+        fee_rate_override: Option<u64>,
+        min_amount: u64,
+        max_amount: u64,
+    ) -> Result<()> {
+        RegisterToken::execute(ctx, fee_rate_override, min_amount, max_amount)
+    }
+
+    pub fn update_token(
+        ctx: Context<UpdateToken>,
+        enabled: bool,
+        fee_rate_override: Option<u64>,
+        min_amount: u64,
+        max_amount: u64,
+    ) -> Result<()> {
+        UpdateToken::execute(ctx, enabled, fee_rate_override, min_amount, max_amount)
+    }
+
+    pub fn disable_token(ctx: Context<DisableToken>) -> Result<()> {
+        DisableToken::execute(ctx)
+    }
+
+    pub fn set_token_mode(ctx: Context<SetTokenMode>, mode: TokenMode) -> Result<()> {
+        SetTokenMode::execute(ctx, mode)
+    }
+
+    pub fn update_rate_limit(
+        ctx: Context<UpdateRateLimit>,
+        max_claim_amount: u64,
+        window_limit: u64,
+        window_duration: i64,
+    ) -> Result<()> {
+        UpdateRateLimit::execute(ctx, max_claim_amount, window_limit, window_duration)
+    }
+
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
+        WithdrawFees::execute(ctx)
+    }
+
+    pub fn update_guardian(ctx: Context<UpdateGuardian>, guardian: Pubkey) -> Result<()> {
+        UpdateGuardian::execute(ctx, guardian)
+    }
+
+    pub fn pause(ctx: Context<Pause>, applies: bool, claims: bool) -> Result<()> {
+        Pause::execute(ctx, applies, claims)
+    }
+
+    pub fn unpause(ctx: Context<Unpause>, applies: bool, claims: bool) -> Result<()> {
+        Unpause::execute(ctx, applies, claims)
+    }
+
+    pub fn pause_token(ctx: Context<PauseToken>, applies: bool, claims: bool) -> Result<()> {
+        PauseToken::execute(ctx, applies, claims)
+    }
+
+    pub fn unpause_token(ctx: Context<UnpauseToken>, applies: bool, claims: bool) -> Result<()> {
+        UnpauseToken::execute(ctx, applies, claims)
+    }
+
+    pub fn register_chain(
+        ctx: Context<RegisterChain>,
+        chain_id: [u8; 8],
+        fee_rate_override: Option<u64>,
+    ) -> Result<()> {
+        RegisterChain::execute(ctx, chain_id, fee_rate_override)
+    }
+
+    pub fn update_chain(ctx: Context<UpdateChain>, enabled: bool, fee_rate_override: Option<u64>) -> Result<()> {
+        UpdateChain::execute(ctx, enabled, fee_rate_override)
+    }
+
+    pub fn register_route(
+        ctx: Context<RegisterRoute>,
+        chain_id: [u8; 8],
+        remote_token: [u8; 32],
+        remote_decimals: u8,
+    ) -> Result<()> {
+        RegisterRoute::execute(ctx, chain_id, remote_token, remote_decimals)
+    }
+
+    pub fn update_route(
+        ctx: Context<UpdateRoute>,
+        remote_token: [u8; 32],
+        remote_decimals: u8,
+        enabled: bool,
+    ) -> Result<()> {
+        UpdateRoute::execute(ctx, remote_token, remote_decimals, enabled)
+    }
+
+    pub fn update_emergency_withdrawal_delay(
+        ctx: Context<UpdateEmergencyWithdrawalDelay>,
+        delay: i64,
+    ) -> Result<()> {
+        UpdateEmergencyWithdrawalDelay::execute(ctx, delay)
+    }
+
+    pub fn schedule_emergency_withdrawal(ctx: Context<ScheduleEmergencyWithdrawal>, amount: u64) -> Result<()> {
+        ScheduleEmergencyWithdrawal::execute(ctx, amount)
+    }
+
+    pub fn execute_emergency_withdrawal(ctx: Context<ExecuteEmergencyWithdrawal>) -> Result<()> {
+        ExecuteEmergencyWithdrawal::execute(ctx)
+    }
+
+    pub fn cancel_emergency_withdrawal(ctx: Context<CancelEmergencyWithdrawal>) -> Result<()> {
+        CancelEmergencyWithdrawal::execute(ctx)
+    }
+
+    pub fn transfer_admin(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<()> {
+        TransferAdmin::execute(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        AcceptAdmin::execute(ctx)
     }
 
     pub fn apply_token(
@@ -37,10 +219,9 @@ pub mod bridge {
         receipient: [u8; 32],
         from_chain_id: [u8; 8],
         from_token: [u8; 32],
-        amount: [u8; 8],
+        amount: [u8; 32],
         to_chain_id: [u8; 8],
         deadline: [u8; 8],
-        signature: [u8; 64],
     ) -> Result<()> {
         ApplyToken::execute(
             ctx,
@@ -52,7 +233,6 @@ pub mod bridge {
             amount,
             to_chain_id,
             deadline,
-            signature,
         )
     }
 
@@ -61,22 +241,192 @@ pub mod bridge {
         order_id: [u8; 32],
         applicant: [u8; 32],
         receipient: [u8; 32],
+        from_chain_id: [u8; 8],
         to_chain_id: [u8; 8],
         to_token: [u8; 32],
-        amount: [u8; 8],
+        amount: [u8; 32],
         deadline: [u8; 8],
-        signature: [u8; 64],
     ) -> Result<()> {
         ClaimToken::execute(
             ctx,
             order_id,
             applicant,
             receipient,
+            from_chain_id,
+            to_chain_id,
+            to_token,
+            amount,
+            deadline,
+        )
+    }
+
+    pub fn deliver_token(
+        ctx: Context<DeliverToken>,
+        order_id: [u8; 32],
+        applicant: [u8; 32],
+        receipient: [u8; 32],
+        from_chain_id: [u8; 8],
+        to_chain_id: [u8; 8],
+        to_token: [u8; 32],
+        amount: [u8; 32],
+        deadline: [u8; 8],
+        relayer_fee: [u8; 32],
+    ) -> Result<()> {
+        DeliverToken::execute(
+            ctx,
+            order_id,
+            applicant,
+            receipient,
+            from_chain_id,
+            to_chain_id,
+            to_token,
+            amount,
+            deadline,
+            relayer_fee,
+        )
+    }
+
+    pub fn refund_apply(
+        ctx: Context<RefundApply>,
+        order_id: [u8; 32],
+        refund_fee: bool,
+        deadline: [u8; 8],
+    ) -> Result<()> {
+        RefundApply::execute(ctx, order_id, refund_fee, deadline)
+    }
+
+    pub fn claim_token_and_call<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimTokenAndCall<'info>>,
+        order_id: [u8; 32],
+        applicant: [u8; 32],
+        receipient: [u8; 32],
+        from_chain_id: [u8; 8],
+        to_chain_id: [u8; 8],
+        to_token: [u8; 32],
+        amount: [u8; 32],
+        deadline: [u8; 8],
+        payload: Vec<u8>,
+    ) -> Result<()> {
+        ClaimTokenAndCall::execute(
+            ctx,
+            order_id,
+            applicant,
+            receipient,
+            from_chain_id,
+            to_chain_id,
+            to_token,
+            amount,
+            deadline,
+            payload,
+        )
+    }
+
+    pub fn apply_sol(
+        ctx: Context<ApplySol>,
+        order_id: [u8; 32],
+        applicant: [u8; 32],
+        receipient: [u8; 32],
+        from_chain_id: [u8; 8],
+        from_token: [u8; 32],
+        amount: [u8; 32],
+        to_chain_id: [u8; 8],
+        deadline: [u8; 8],
+    ) -> Result<()> {
+        ApplySol::execute(
+            ctx,
+            order_id,
+            applicant,
+            receipient,
+            from_chain_id,
+            from_token,
+            amount,
+            to_chain_id,
+            deadline,
+        )
+    }
+
+    pub fn claim_sol(
+        ctx: Context<ClaimSol>,
+        order_id: [u8; 32],
+        applicant: [u8; 32],
+        receipient: [u8; 32],
+        from_chain_id: [u8; 8],
+        to_chain_id: [u8; 8],
+        to_token: [u8; 32],
+        amount: [u8; 32],
+        deadline: [u8; 8],
+    ) -> Result<()> {
+        ClaimSol::execute(
+            ctx,
+            order_id,
+            applicant,
+            receipient,
+            from_chain_id,
             to_chain_id,
             to_token,
             amount,
             deadline,
-            signature,
         )
     }
+
+    pub fn refund_sol_apply(
+        ctx: Context<RefundSolApply>,
+        order_id: [u8; 32],
+        refund_fee: bool,
+        deadline: [u8; 8],
+    ) -> Result<()> {
+        RefundSolApply::execute(ctx, order_id, refund_fee, deadline)
+    }
+
+    pub fn initialize_nft_config(ctx: Context<InitializeNftConfig>, fee: u64) -> Result<()> {
+        InitializeNftConfig::execute(ctx, fee)
+    }
+
+    pub fn update_nft_fee_currency(ctx: Context<UpdateNftFeeCurrency>) -> Result<()> {
+        UpdateNftFeeCurrency::execute(ctx)
+    }
+
+    pub fn update_nft_fee(ctx: Context<UpdateNftFee>, fee: u64) -> Result<()> {
+        UpdateNftFee::execute(ctx, fee)
+    }
+
+    pub fn add_supported_collection(ctx: Context<AddSupportedCollection>) -> Result<()> {
+        AddSupportedCollection::execute(ctx)
+    }
+
+    pub fn remove_supported_collection(ctx: Context<RemoveSupportedCollection>) -> Result<()> {
+        RemoveSupportedCollection::execute(ctx)
+    }
+
+    pub fn apply_nft(
+        ctx: Context<ApplyNft>,
+        order_id: [u8; 32],
+        applicant: [u8; 32],
+        receipient: [u8; 32],
+        from_chain_id: [u8; 8],
+        to_chain_id: [u8; 8],
+        deadline: [u8; 8],
+    ) -> Result<()> {
+        ApplyNft::execute(ctx, order_id, applicant, receipient, from_chain_id, to_chain_id, deadline)
+    }
+
+    pub fn claim_nft(
+        ctx: Context<ClaimNft>,
+        order_id: [u8; 32],
+        applicant: [u8; 32],
+        receipient: [u8; 32],
+        from_chain_id: [u8; 8],
+        to_chain_id: [u8; 8],
+        deadline: [u8; 8],
+    ) -> Result<()> {
+        ClaimNft::execute(ctx, order_id, applicant, receipient, from_chain_id, to_chain_id, deadline)
+    }
+
+    pub fn add_call_target(ctx: Context<AddCallTarget>) -> Result<()> {
+        AddCallTarget::execute(ctx)
+    }
+
+    pub fn remove_call_target(ctx: Context<RemoveCallTarget>) -> Result<()> {
+        RemoveCallTarget::execute(ctx)
+    }
 }
@@ -0,0 +1,352 @@
+//! Byte layouts of the messages validators sign for each bridge order.
+//!
+//! With `SignatureScheme::Ed25519` the fields are concatenated as-is. With
+//! `SignatureScheme::Secp256k1` they are packed like Solidity's
+//! `abi.encodePacked` (8-byte integers widened to `uint256`), hashed with
+//! keccak256 and wrapped in the `"\x19Ethereum Signed Message:\n32"` prefix, so
+//! the recovered address is the one `Signature.getSigner` yields on EVM chains.
+//!
+//! Versioned messages start with a header binding them to one deployment:
+//! `MESSAGE_PREFIX`, `MESSAGE_VERSION`, the program id, the local chain id and
+//! the order direction. Their amounts are canonical: a big-endian `uint256`
+//! with `CANONICAL_DECIMALS` decimals. The legacy layout has no header, carries
+//! amounts in local token units and is only accepted until
+//! `GlobalAccount::legacy_messages_until`. Versioned claims also carry the
+//! source chain of the order, which the legacy claim layout never had.
+//!
+//! Call payloads of `claim_token_and_call` are signed as their keccak256 hash.
+use crate::program_accounts::SignatureScheme;
+use anchor_lang::prelude::Pubkey;
+use solana_program::keccak;
+
+pub const MESSAGE_PREFIX: &[u8] = b"KEPLR_BRIDGE";
+pub const MESSAGE_VERSION: u8 = 2;
+
+/// Which layout the validators signed an order in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    Versioned,
+    Legacy,
+}
+
+/// The deployment a versioned message is valid for.
+#[derive(Debug, Clone, Copy)]
+pub struct Domain {
+    pub program_id: Pubkey,
+    pub chain_id: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Apply = 0,
+    Claim = 1,
+    Deliver = 2,
+    Refund = 3,
+    ApplyNft = 4,
+    ClaimNft = 5,
+    ClaimAndCall = 6,
+}
+
+enum Field<'a> {
+    Bytes(&'a [u8]),
+    Bytes32(&'a [u8; 32]),
+    Uint(&'a [u8; 8]),
+    Byte(u8),
+    Bool(bool),
+}
+
+fn encode(scheme: SignatureScheme, header: Option<(&Domain, Direction)>, fields: &[Field]) -> Vec<u8> {
+    let mut packed: Vec<u8> = Vec::new();
+    if let Some((domain, direction)) = header {
+        let program_id = domain.program_id.to_bytes();
+        let chain_id = domain.chain_id.to_be_bytes();
+        for field in &[
+            Field::Bytes(MESSAGE_PREFIX),
+            Field::Byte(MESSAGE_VERSION),
+            Field::Bytes32(&program_id),
+            Field::Uint(&chain_id),
+            Field::Byte(direction as u8),
+        ] {
+            pack(scheme, &mut packed, field);
+        }
+    }
+    for field in fields {
+        pack(scheme, &mut packed, field);
+    }
+
+    match scheme {
+        SignatureScheme::Ed25519 => packed,
+        SignatureScheme::Secp256k1 => eth_signed_message(&keccak::hash(&packed).to_bytes()),
+    }
+}
+
+fn pack(scheme: SignatureScheme, packed: &mut Vec<u8>, field: &Field) {
+    match (scheme, field) {
+        (_, Field::Bytes(value)) => packed.extend_from_slice(value),
+        (_, Field::Bytes32(value)) => packed.extend_from_slice(*value),
+        (SignatureScheme::Ed25519, Field::Uint(value)) => packed.extend_from_slice(*value),
+        (SignatureScheme::Secp256k1, Field::Uint(value)) => {
+            packed.extend_from_slice(&[0u8; 24]);
+            packed.extend_from_slice(*value);
+        }
+        (_, Field::Byte(value)) => packed.push(*value),
+        (_, Field::Bool(value)) => packed.push(*value as u8),
+    }
+}
+
+fn eth_signed_message(hash: &[u8; 32]) -> Vec<u8> {
+    let mut msg: Vec<u8> = b"\x19Ethereum Signed Message:\n32".to_vec();
+    msg.extend_from_slice(hash);
+    msg
+}
+
+pub fn apply_token_message(
+    scheme: SignatureScheme,
+    domain: &Domain,
+    order_id: &[u8; 32],
+    applicant: &[u8; 32],
+    receipient: &[u8; 32],
+    from_chain_id: &[u8; 8],
+    from_token: &[u8; 32],
+    amount: &[u8; 32],
+    to_chain_id: &[u8; 8],
+    deadline: &[u8; 8],
+) -> Vec<u8> {
+    encode(
+        scheme,
+        Some((domain, Direction::Apply)),
+        &[
+            Field::Bytes32(order_id),
+            Field::Bytes32(applicant),
+            Field::Bytes32(receipient),
+            Field::Uint(from_chain_id),
+            Field::Bytes32(from_token),
+            Field::Bytes32(amount),
+            Field::Uint(to_chain_id),
+            Field::Uint(deadline),
+        ],
+    )
+}
+
+pub fn legacy_apply_token_message(
+    scheme: SignatureScheme,
+    order_id: &[u8; 32],
+    applicant: &[u8; 32],
+    receipient: &[u8; 32],
+    from_chain_id: &[u8; 8],
+    from_token: &[u8; 32],
+    amount: &[u8; 8],
+    to_chain_id: &[u8; 8],
+    deadline: &[u8; 8],
+) -> Vec<u8> {
+    encode(
+        scheme,
+        None,
+        &[
+            Field::Bytes32(order_id),
+            Field::Bytes32(applicant),
+            Field::Bytes32(receipient),
+            Field::Uint(from_chain_id),
+            Field::Bytes32(from_token),
+            Field::Uint(amount),
+            Field::Uint(to_chain_id),
+            Field::Uint(deadline),
+        ],
+    )
+}
+
+pub fn claim_token_message(
+    scheme: SignatureScheme,
+    domain: &Domain,
+    order_id: &[u8; 32],
+    applicant: &[u8; 32],
+    receipient: &[u8; 32],
+    from_chain_id: &[u8; 8],
+    to_chain_id: &[u8; 8],
+    to_token: &[u8; 32],
+    amount: &[u8; 32],
+    deadline: &[u8; 8],
+) -> Vec<u8> {
+    encode(
+        scheme,
+        Some((domain, Direction::Claim)),
+        &[
+            Field::Bytes32(order_id),
+            Field::Bytes32(applicant),
+            Field::Bytes32(receipient),
+            Field::Uint(from_chain_id),
+            Field::Uint(to_chain_id),
+            Field::Bytes32(to_token),
+            Field::Bytes32(amount),
+            Field::Uint(deadline),
+        ],
+    )
+}
+
+pub fn legacy_claim_token_message(
+    scheme: SignatureScheme,
+    order_id: &[u8; 32],
+    applicant: &[u8; 32],
+    receipient: &[u8; 32],
+    to_chain_id: &[u8; 8],
+    to_token: &[u8; 32],
+    amount: &[u8; 8],
+    deadline: &[u8; 8],
+) -> Vec<u8> {
+    encode(
+        scheme,
+        None,
+        &[
+            Field::Bytes32(order_id),
+            Field::Bytes32(applicant),
+            Field::Bytes32(receipient),
+            Field::Uint(to_chain_id),
+            Field::Bytes32(to_token),
+            Field::Uint(amount),
+            Field::Uint(deadline),
+        ],
+    )
+}
+
+/// The claim message with the relayer fee appended, so a signature for a
+/// self-service claim can never be delivered by a relayer and vice versa.
+pub fn deliver_token_message(
+    scheme: SignatureScheme,
+    domain: &Domain,
+    order_id: &[u8; 32],
+    applicant: &[u8; 32],
+    receipient: &[u8; 32],
+    from_chain_id: &[u8; 8],
+    to_chain_id: &[u8; 8],
+    to_token: &[u8; 32],
+    amount: &[u8; 32],
+    deadline: &[u8; 8],
+    relayer_fee: &[u8; 32],
+) -> Vec<u8> {
+    encode(
+        scheme,
+        Some((domain, Direction::Deliver)),
+        &[
+            Field::Bytes32(order_id),
+            Field::Bytes32(applicant),
+            Field::Bytes32(receipient),
+            Field::Uint(from_chain_id),
+            Field::Uint(to_chain_id),
+            Field::Bytes32(to_token),
+            Field::Bytes32(amount),
+            Field::Uint(deadline),
+            Field::Bytes32(relayer_fee),
+        ],
+    )
+}
+
+pub fn refund_apply_message(
+    scheme: SignatureScheme,
+    domain: &Domain,
+    order_id: &[u8; 32],
+    applicant: &[u8; 32],
+    refund_fee: bool,
+    deadline: &[u8; 8],
+) -> Vec<u8> {
+    encode(
+        scheme,
+        Some((domain, Direction::Refund)),
+        &[
+            Field::Bytes32(order_id),
+            Field::Bytes32(applicant),
+            Field::Bool(refund_fee),
+            Field::Uint(deadline),
+        ],
+    )
+}
+
+/// `nft` is the collection and `token_id` the NFT mint, in the places of
+/// `fromNFT` and the single-element `fromTokenIds` of the EVM bridge.
+pub fn apply_nft_message(
+    scheme: SignatureScheme,
+    domain: &Domain,
+    order_id: &[u8; 32],
+    applicant: &[u8; 32],
+    receipient: &[u8; 32],
+    from_chain_id: &[u8; 8],
+    nft: &[u8; 32],
+    token_id: &[u8; 32],
+    to_chain_id: &[u8; 8],
+    deadline: &[u8; 8],
+) -> Vec<u8> {
+    encode(
+        scheme,
+        Some((domain, Direction::ApplyNft)),
+        &[
+            Field::Bytes32(order_id),
+            Field::Bytes32(applicant),
+            Field::Bytes32(receipient),
+            Field::Uint(from_chain_id),
+            Field::Bytes32(nft),
+            Field::Bytes32(token_id),
+            Field::Uint(to_chain_id),
+            Field::Uint(deadline),
+        ],
+    )
+}
+
+pub fn claim_nft_message(
+    scheme: SignatureScheme,
+    domain: &Domain,
+    order_id: &[u8; 32],
+    applicant: &[u8; 32],
+    receipient: &[u8; 32],
+    from_chain_id: &[u8; 8],
+    to_chain_id: &[u8; 8],
+    nft: &[u8; 32],
+    token_id: &[u8; 32],
+    deadline: &[u8; 8],
+) -> Vec<u8> {
+    encode(
+        scheme,
+        Some((domain, Direction::ClaimNft)),
+        &[
+            Field::Bytes32(order_id),
+            Field::Bytes32(applicant),
+            Field::Bytes32(receipient),
+            Field::Uint(from_chain_id),
+            Field::Uint(to_chain_id),
+            Field::Bytes32(nft),
+            Field::Bytes32(token_id),
+            Field::Uint(deadline),
+        ],
+    )
+}
+
+pub fn claim_and_call_message(
+    scheme: SignatureScheme,
+    domain: &Domain,
+    order_id: &[u8; 32],
+    applicant: &[u8; 32],
+    receipient: &[u8; 32],
+    from_chain_id: &[u8; 8],
+    to_chain_id: &[u8; 8],
+    to_token: &[u8; 32],
+    amount: &[u8; 32],
+    deadline: &[u8; 8],
+    target_program: &[u8; 32],
+    payload: &[u8],
+) -> Vec<u8> {
+    let payload_hash = keccak::hash(payload).to_bytes();
+    encode(
+        scheme,
+        Some((domain, Direction::ClaimAndCall)),
+        &[
+            Field::Bytes32(order_id),
+            Field::Bytes32(applicant),
+            Field::Bytes32(receipient),
+            Field::Uint(from_chain_id),
+            Field::Uint(to_chain_id),
+            Field::Bytes32(to_token),
+            Field::Bytes32(amount),
+            Field::Uint(deadline),
+            Field::Bytes32(target_program),
+            Field::Bytes32(&payload_hash),
+        ],
+    )
+}
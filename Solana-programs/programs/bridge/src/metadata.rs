@@ -0,0 +1,163 @@
+//! Minimal reader for Metaplex token metadata accounts, enough to find the
+//! verified collection of an NFT without depending on the metadata crate.
+//!
+//! Follows the `Metadata` layout of mpl-token-metadata 1.1.0, the first release
+//! with collections. Later releases only append fields after `collection`, and
+//! accounts written before it are zero-padded, so both read as `None` there.
+use anchor_lang::prelude::*;
+
+pub mod metadata_program {
+    anchor_lang::declare_id!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+}
+
+#[derive(AnchorDeserialize)]
+struct Creator {
+    _address: Pubkey,
+    _verified: bool,
+    _share: u8,
+}
+
+#[derive(AnchorDeserialize)]
+struct Collection {
+    verified: bool,
+    key: Pubkey,
+}
+
+/// The leading fields of a `Metadata` account, in their Borsh layout, up to
+/// the collection.
+#[derive(AnchorDeserialize)]
+struct MetadataPrefix {
+    _key: u8,
+    _update_authority: Pubkey,
+    mint: Pubkey,
+    _name: String,
+    _symbol: String,
+    _uri: String,
+    _seller_fee_basis_points: u16,
+    _creators: Option<Vec<Creator>>,
+    _primary_sale_happened: bool,
+    _is_mutable: bool,
+    _edition_nonce: Option<u8>,
+    _token_standard: Option<u8>,
+    collection: Option<Collection>,
+}
+
+/// The collection `mint` belongs to, if its metadata records a verified one.
+pub fn verified_collection(metadata: &AccountInfo, mint: &Pubkey) -> Option<Pubkey> {
+    let data = metadata.try_borrow_data().ok()?;
+    let prefix = MetadataPrefix::deserialize(&mut &data[..]).ok()?;
+    if prefix.mint != *mint {
+        return None;
+    }
+    match prefix.collection {
+        Some(collection) if collection.verified => Some(collection.key),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mpl_token_metadata::state::{
+        Collection as MplCollection, Creator as MplCreator, Data, Key, Metadata, TokenStandard, MAX_METADATA_LEN,
+    };
+
+    fn metadata(mint: Pubkey, token_standard: Option<TokenStandard>, collection: Option<MplCollection>) -> Vec<u8> {
+        let metadata = Metadata {
+            key: Key::MetadataV1,
+            update_authority: Pubkey::new_unique(),
+            mint,
+            data: Data {
+                name: "Bridged #1".to_string(),
+                symbol: "BRDG".to_string(),
+                uri: "https://example.com/1.json".to_string(),
+                seller_fee_basis_points: 500,
+                creators: Some(vec![MplCreator {
+                    address: Pubkey::new_unique(),
+                    verified: true,
+                    share: 100,
+                }]),
+            },
+            primary_sale_happened: true,
+            is_mutable: true,
+            edition_nonce: Some(254),
+            token_standard,
+            collection,
+            uses: None,
+        };
+        let mut data = metadata.try_to_vec().unwrap();
+        data.resize(MAX_METADATA_LEN, 0);
+        data
+    }
+
+    fn read(mut data: Vec<u8>, mint: &Pubkey) -> Option<Pubkey> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &metadata_program::ID,
+            false,
+            0,
+        );
+        verified_collection(&account, mint)
+    }
+
+    #[test]
+    fn verified_collection_is_read() {
+        let mint = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+        let data = metadata(
+            mint,
+            Some(TokenStandard::NonFungible),
+            Some(MplCollection {
+                verified: true,
+                key: collection,
+            }),
+        );
+        assert_eq!(read(data, &mint), Some(collection));
+    }
+
+    #[test]
+    fn unverified_or_missing_collection_is_ignored() {
+        let mint = Pubkey::new_unique();
+        let unverified = metadata(
+            mint,
+            Some(TokenStandard::NonFungible),
+            Some(MplCollection {
+                verified: false,
+                key: Pubkey::new_unique(),
+            }),
+        );
+        assert_eq!(read(unverified, &mint), None);
+        assert_eq!(read(metadata(mint, None, None), &mint), None);
+    }
+
+    #[test]
+    fn collection_without_token_standard_is_read() {
+        let mint = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+        let data = metadata(
+            mint,
+            None,
+            Some(MplCollection {
+                verified: true,
+                key: collection,
+            }),
+        );
+        assert_eq!(read(data, &mint), Some(collection));
+    }
+
+    #[test]
+    fn metadata_of_another_mint_is_ignored() {
+        let collection = MplCollection {
+            verified: true,
+            key: Pubkey::new_unique(),
+        };
+        let data = metadata(Pubkey::new_unique(), None, Some(collection));
+        assert_eq!(read(data, &Pubkey::new_unique()), None);
+    }
+}
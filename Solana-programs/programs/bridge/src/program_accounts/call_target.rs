@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+/// Allows `claim_token_and_call` to invoke `program_id`, stored at
+/// `["call-target", program_id]`. Removing it closes the account.
+#[account]
+pub struct CallTarget {
+    pub program_id: Pubkey,
+}
+
+impl CallTarget {
+    pub const LEN: usize = 8 + 32;
+}
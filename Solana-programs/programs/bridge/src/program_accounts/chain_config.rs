@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Supported remote chain, stored at `["chain", chain_id]` with the chain id in
+/// big-endian bytes, as it appears in signed orders.
+#[account]
+pub struct ChainConfig {
+    pub chain_id: u64,
+
+    pub enabled: bool,
+
+    /// Replaces `GlobalAccount.token_fee_rate` for applies to this chain.
+    pub fee_rate_override: Option<u64>,
+}
+
+impl ChainConfig {
+    pub const LEN: usize = 8 + 8 + 1 + 1 + 8;
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// A pending withdrawal from a vault outside the claim flow, stored at
+/// `["emergency-withdrawal", mint]`. At most one can be pending per mint.
+#[account]
+pub struct EmergencyWithdrawal {
+    pub mint: Pubkey,
+
+    pub amount: u64,
+
+    /// Token account that receives the funds.
+    pub destination: Pubkey,
+
+    pub scheduled_at: i64,
+
+    /// Unix timestamp from which the withdrawal can be executed.
+    pub executable_at: i64,
+
+    /// Guardian at scheduling time, who can still cancel after being replaced.
+    pub guardian: Pubkey,
+}
+
+impl EmergencyWithdrawal {
+    pub const LEN: usize = 8 + 32 + 8 + 32 + 8 + 8 + 32;
+}
@@ -1,8 +1,103 @@
+use crate::messages::Domain;
 use anchor_lang::prelude::*;
 
+/// Denominator of `token_fee_rate`, i.e. a rate of `FEE_RATE_DENOMINATOR` is 100%.
+pub const FEE_RATE_DENOMINATOR: u64 = 1_000_000_000;
+
+/// Shortest delay, in seconds, between scheduling and executing an emergency withdrawal.
+pub const MIN_EMERGENCY_WITHDRAWAL_DELAY: i64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum FeeMode {
+    /// The user pays `amount + fee` and `amount` is bridged.
+    OnTop,
+    /// The user pays `amount` and `amount - fee` is bridged.
+    Deducted,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum FeeRounding {
+    Up,
+    Down,
+}
+
+/// Bridge-wide settings, stored at `["global-account-02"]`. The layout is not
+/// compatible with accounts written by earlier versions of the program, so
+/// upgrading an existing deployment requires redeploying under a new program
+/// id and re-running `initialize`.
 #[account]
 pub struct GlobalAccount {
-    pub signer: [u8; 32],
+    pub admin: Pubkey,
+
+    pub pending_admin: Pubkey,
 
     pub token_fee_rate: u64,
+
+    /// Owner of the token accounts that receive withdrawn fees.
+    pub treasury: Pubkey,
+
+    pub fee_mode: FeeMode,
+
+    pub fee_rounding: FeeRounding,
+
+    /// Can pause the bridge, but only the admin can unpause it.
+    pub guardian: Pubkey,
+
+    pub applies_paused: bool,
+
+    pub claims_paused: bool,
+
+    /// Id of the chain this deployment serves, bound into every signed message.
+    pub chain_id: u64,
+
+    /// Unix timestamp until which messages in the legacy, unversioned layout
+    /// are still accepted for applies and claims.
+    pub legacy_messages_until: i64,
+
+    /// Seconds a scheduled emergency withdrawal waits before it can be executed,
+    /// never below `MIN_EMERGENCY_WITHDRAWAL_DELAY`.
+    pub emergency_withdrawal_delay: i64,
+}
+
+impl GlobalAccount {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 32 + 1 + 1 + 32 + 1 + 1 + 8 + 8 + 8;
+
+    pub fn is_guardian_or_admin(&self, key: &Pubkey) -> bool {
+        *key == self.guardian || *key == self.admin
+    }
+
+    pub fn message_domain(&self) -> Domain {
+        Domain {
+            program_id: crate::ID,
+            chain_id: self.chain_id,
+        }
+    }
+
+    pub fn accepts_legacy_messages(&self, now: i64) -> bool {
+        now <= self.legacy_messages_until
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn len_matches_serialized_size() {
+        let global = GlobalAccount {
+            admin: Pubkey::default(),
+            pending_admin: Pubkey::default(),
+            token_fee_rate: 0,
+            treasury: Pubkey::default(),
+            fee_mode: FeeMode::OnTop,
+            fee_rounding: FeeRounding::Up,
+            guardian: Pubkey::default(),
+            applies_paused: false,
+            claims_paused: false,
+            chain_id: 0,
+            legacy_messages_until: 0,
+            emergency_withdrawal_delay: 0,
+        };
+        assert_eq!(8 + global.try_to_vec().unwrap().len(), GlobalAccount::LEN);
+    }
 }
@@ -1,5 +1,23 @@
+mod call_target;
+mod chain_config;
+mod emergency_withdrawal;
 mod global;
-mod user;
+mod nft_config;
+mod order;
+mod supported_collection;
+mod token_config;
+mod token_registry;
+mod token_route;
+mod validator_set;
 
-pub use global::GlobalAccount;
-pub use user::{TokenApply, TokenClaim, UserAccount};
+pub use call_target::CallTarget;
+pub use chain_config::ChainConfig;
+pub use emergency_withdrawal::EmergencyWithdrawal;
+pub use global::{FeeMode, FeeRounding, GlobalAccount, FEE_RATE_DENOMINATOR, MIN_EMERGENCY_WITHDRAWAL_DELAY};
+pub use nft_config::NftConfig;
+pub use order::{OrderAccount, OrderDirection, OrderStatus};
+pub use supported_collection::SupportedCollection;
+pub use token_config::{TokenConfig, TokenMode};
+pub use token_registry::{TokenRegistry, MAX_TOKENS};
+pub use token_route::TokenRoute;
+pub use validator_set::{SignatureScheme, ValidatorSet, MAX_VALIDATORS};
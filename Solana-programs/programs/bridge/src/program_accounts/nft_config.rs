@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// NFT bridging settings, stored at `["nft-config"]`, mirroring
+/// `nftFeeCurrency`/`nftFee` of the EVM bridge.
+#[account]
+pub struct NftConfig {
+    /// Mint of the SPL token the flat NFT fee is paid in.
+    pub fee_currency: Pubkey,
+
+    /// Fee charged per applied NFT, paid to the treasury.
+    pub fee: u64,
+}
+
+impl NftConfig {
+    pub const LEN: usize = 8 + 32 + 8;
+}
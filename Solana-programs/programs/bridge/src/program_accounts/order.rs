@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum OrderDirection {
+    Apply,
+    Claim,
+    ApplyNft,
+    ClaimNft,
+    /// Native SOL orders, see `apply_sol`/`claim_sol`.
+    ApplySol,
+    ClaimSol,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum OrderStatus {
+    Completed,
+    /// The locked funds went back to the applicant; validators must never
+    /// sign the matching claim on the destination chain.
+    Refunded,
+}
+
+/// Receipt of a processed order, stored at `["apply-order", order_id]` or
+/// `["claim-order", order_id]`. Its existence is the replay protection.
+#[account]
+pub struct OrderAccount {
+    pub order_id: [u8; 32],
+
+    pub direction: OrderDirection,
+
+    pub status: OrderStatus,
+
+    pub applicant: [u8; 32],
+
+    pub receipient: [u8; 32],
+
+    /// Zero for claims signed in the legacy layout, which lacks the source chain.
+    pub from_chain_id: u64,
+
+    pub to_chain_id: u64,
+
+    /// `from_token` for applies, `to_token` for claims.
+    pub token: [u8; 32],
+
+    pub mint: Pubkey,
+
+    /// Amount bridged to the destination chain, excluding `fee`.
+    pub amount: u64,
+
+    pub fee: u64,
+
+    pub slot: u64,
+
+    pub timestamp: i64,
+}
+
+impl OrderAccount {
+    pub const LEN: usize = 8 + 32 + 1 + 1 + 32 + 32 + 8 + 8 + 32 + 32 + 8 + 8 + 8 + 8;
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// Marks a Metaplex collection as bridgeable, stored at
+/// `["nft-collection", collection]`.
+#[account]
+pub struct SupportedCollection {
+    pub collection: Pubkey,
+
+    /// Cleared when support is removed, which stops both applies and claims
+    /// of the collection until it is added again.
+    pub enabled: bool,
+}
+
+impl SupportedCollection {
+    pub const LEN: usize = 8 + 32 + 1;
+}
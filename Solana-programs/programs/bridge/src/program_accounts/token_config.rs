@@ -0,0 +1,164 @@
+use crate::errors::BridgeErrors;
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum TokenMode {
+    /// Native tokens: applies lock into the vault, claims release from it.
+    LockUnlock,
+    /// Wrapped tokens: applies burn, claims mint through the `["mint-authority", mint]` PDA.
+    MintBurn,
+}
+
+/// Per-mint bridge state, stored at `["token-config", mint]`.
+#[account]
+pub struct TokenConfig {
+    pub mint: Pubkey,
+
+    pub mode: TokenMode,
+
+    pub applies_paused: bool,
+
+    pub claims_paused: bool,
+
+    /// Fees held in the vault that have not been withdrawn to the treasury yet.
+    pub accrued_fees: u64,
+
+    pub total_fees_collected: u64,
+
+    /// Largest amount a single claim may pay out, 0 for no limit.
+    pub max_claim_amount: u64,
+
+    /// Outflow allowed per `window_duration` seconds, 0 for no limit.
+    pub window_limit: u64,
+
+    pub window_duration: i64,
+
+    /// Outflow still counted against the window as of `window_updated_at`.
+    pub window_claimed: u64,
+
+    pub window_updated_at: i64,
+
+    /// Cleared by `disable_token`; applies and claims are rejected while false.
+    pub enabled: bool,
+
+    /// Takes precedence over the chain and global fee rates when set.
+    pub fee_rate_override: Option<u64>,
+
+    /// Smallest amount a single apply may lock or burn, in local units.
+    pub min_amount: u64,
+
+    /// Largest amount a single apply may lock or burn, 0 for no limit.
+    pub max_amount: u64,
+}
+
+impl TokenConfig {
+    pub const LEN: usize = 8 + 32 + 1 + 1 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 9 + 8 + 8;
+
+    /// Checks an apply of `amount` against the per-token bounds.
+    pub fn check_apply_amount(&self, amount: u64) -> Result<()> {
+        if amount < self.min_amount {
+            return Err(BridgeErrors::AmountBelowMinimum.into());
+        }
+        if self.max_amount > 0 && amount > self.max_amount {
+            return Err(BridgeErrors::AmountAboveMaximum.into());
+        }
+        Ok(())
+    }
+
+    /// Books a fee taken on apply, withdrawable by the admin.
+    pub fn accrue_fee(&mut self, fee: u64) -> Result<()> {
+        self.accrued_fees = self.accrued_fees.checked_add(fee).ok_or(BridgeErrors::MathOverflow)?;
+        self.total_fees_collected = self
+            .total_fees_collected
+            .checked_add(fee)
+            .ok_or(BridgeErrors::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Records a claim of `amount` against the outflow limits. Past outflow is
+    /// released linearly over `window_duration`, so a full `window_limit` only
+    /// becomes available again after a whole window without claims.
+    pub fn consume_outflow(&mut self, amount: u64, now: i64) -> Result<()> {
+        if self.max_claim_amount > 0 && amount > self.max_claim_amount {
+            return Err(BridgeErrors::ClaimAmountExceedsLimit.into());
+        }
+        if self.window_limit == 0 || self.window_duration <= 0 {
+            return Ok(());
+        }
+
+        let elapsed = now.saturating_sub(self.window_updated_at).max(0) as u128;
+        let released = self.window_limit as u128 * elapsed / self.window_duration as u128;
+        let claimed = (self.window_claimed as u128).saturating_sub(released) + amount as u128;
+        if claimed > self.window_limit as u128 {
+            return Err(BridgeErrors::RateLimitExceeded.into());
+        }
+        self.window_claimed = claimed as u64;
+        self.window_updated_at = now;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_error;
+
+    fn token_config(max_claim_amount: u64, window_limit: u64, window_duration: i64) -> TokenConfig {
+        TokenConfig {
+            mint: Pubkey::default(),
+            mode: TokenMode::LockUnlock,
+            applies_paused: false,
+            claims_paused: false,
+            accrued_fees: 0,
+            total_fees_collected: 0,
+            max_claim_amount,
+            window_limit,
+            window_duration,
+            window_claimed: 0,
+            window_updated_at: 0,
+            enabled: true,
+            fee_rate_override: None,
+            min_amount: 0,
+            max_amount: 0,
+        }
+    }
+
+    #[test]
+    fn exact_limits_are_allowed() {
+        let mut config = token_config(600, 1_000, 100);
+        config.consume_outflow(600, 0).unwrap();
+        config.consume_outflow(400, 0).unwrap();
+        assert_eq!(config.window_claimed, 1_000);
+        assert_error(config.consume_outflow(1, 0), BridgeErrors::RateLimitExceeded);
+        assert_error(config.consume_outflow(601, 100), BridgeErrors::ClaimAmountExceedsLimit);
+    }
+
+    #[test]
+    fn window_releases_linearly() {
+        let mut config = token_config(0, 1_000, 100);
+        config.consume_outflow(1_000, 0).unwrap();
+        // A tenth of the window releases a tenth of the limit.
+        assert_error(config.consume_outflow(101, 10), BridgeErrors::RateLimitExceeded);
+        config.consume_outflow(100, 10).unwrap();
+        assert_eq!((config.window_claimed, config.window_updated_at), (1_000, 10));
+    }
+
+    #[test]
+    fn window_rolls_over() {
+        let mut config = token_config(0, 1_000, 100);
+        config.consume_outflow(1_000, 0).unwrap();
+        config.consume_outflow(1_000, 100).unwrap();
+        // Gaps longer than a window do not bank extra capacity.
+        config.consume_outflow(1_000, 1_000).unwrap();
+        assert_eq!(config.window_claimed, 1_000);
+        assert_error(config.consume_outflow(1, 1_000), BridgeErrors::RateLimitExceeded);
+    }
+
+    #[test]
+    fn unlimited_without_a_window() {
+        let mut config = token_config(0, 0, 0);
+        config.consume_outflow(u64::MAX, 0).unwrap();
+        config.consume_outflow(u64::MAX, 0).unwrap();
+        assert_eq!(config.window_claimed, 0);
+    }
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_TOKENS: usize = 64;
+
+/// Every mint that went through `register_token`, stored at `["token-registry"]`.
+/// Whether a token is currently bridgeable is tracked by `TokenConfig::enabled`.
+#[account]
+pub struct TokenRegistry {
+    pub tokens: Vec<Pubkey>,
+}
+
+impl TokenRegistry {
+    pub const LEN: usize = 8 + 4 + 32 * MAX_TOKENS;
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Maps a local mint to its token on a remote chain, stored at
+/// `["route", mint, chain_id]`.
+#[account]
+pub struct TokenRoute {
+    pub mint: Pubkey,
+
+    pub chain_id: u64,
+
+    pub remote_token: [u8; 32],
+
+    pub enabled: bool,
+
+    /// Decimals of `remote_token`; applies must be expressible in them.
+    pub remote_decimals: u8,
+}
+
+impl TokenRoute {
+    pub const LEN: usize = 8 + 32 + 8 + 32 + 1 + 1;
+}
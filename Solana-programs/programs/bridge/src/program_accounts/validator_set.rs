@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_VALIDATORS: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum SignatureScheme {
+    /// Validators are ed25519 public keys, verified through the Ed25519 program.
+    Ed25519,
+    /// Validators are Ethereum addresses left-padded to 32 bytes, verified
+    /// through the Secp256k1 program.
+    Secp256k1,
+}
+
+#[account]
+pub struct ValidatorSet {
+    pub scheme: SignatureScheme,
+
+    pub threshold: u8,
+
+    pub validators: Vec<[u8; 32]>,
+}
+
+impl ValidatorSet {
+    pub const LEN: usize = 8 + 1 + 1 + 4 + 32 * MAX_VALIDATORS;
+
+    pub fn contains(&self, pubkey: &[u8; 32]) -> bool {
+        self.validators.iter().any(|validator| validator == pubkey)
+    }
+
+    pub fn is_valid_key(scheme: SignatureScheme, pubkey: &[u8; 32]) -> bool {
+        match scheme {
+            SignatureScheme::Ed25519 => pubkey != &[0u8; 32],
+            SignatureScheme::Secp256k1 => pubkey[..12] == [0u8; 12] && pubkey[12..] != [0u8; 20],
+        }
+    }
+}
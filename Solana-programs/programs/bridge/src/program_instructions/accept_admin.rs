@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, seeds = [b"global-account-02"], bump, constraint = global_account.pending_admin == pending_admin.key() @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub pending_admin: Signer<'info>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn execute(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let global = &mut ctx.accounts.global_account;
+        let previous_admin = global.admin;
+        global.admin = global.pending_admin;
+        global.pending_admin = Pubkey::default();
+        emit!(AdminTransferred {
+            previous_admin,
+            admin: global.admin,
+        });
+        Ok(())
+    }
+}
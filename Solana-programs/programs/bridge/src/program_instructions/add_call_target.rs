@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AddCallTarget<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account( init, payer = admin, seeds = [b"call-target", target_program.key().as_ref()], bump, space = CallTarget::LEN )]
+    pub call_target: Account<'info, CallTarget>,
+
+    /// CHECK: only its key is recorded, it must be an executable program.
+    #[account(constraint = target_program.executable @ BridgeErrors::InvalidCallTarget)]
+    pub target_program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddCallTarget<'info> {
+    pub fn execute(ctx: Context<AddCallTarget>) -> Result<()> {
+        let program_id = ctx.accounts.target_program.key();
+        ctx.accounts.call_target.program_id = program_id;
+        emit!(CallTargetUpdated {
+            program_id,
+            allowed: true,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct AddSupportedCollection<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account( init_if_needed, payer = admin, seeds = [b"nft-collection", collection.key().as_ref()], bump, space = SupportedCollection::LEN )]
+    pub supported_collection: Account<'info, SupportedCollection>,
+
+    /// Mint of the Metaplex collection NFT.
+    pub collection: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddSupportedCollection<'info> {
+    pub fn execute(ctx: Context<AddSupportedCollection>) -> Result<()> {
+        let collection = ctx.accounts.collection.key();
+        let supported_collection = &mut ctx.accounts.supported_collection;
+        supported_collection.collection = collection;
+        supported_collection.enabled = true;
+        emit!(SupportedCollectionUpdated {
+            collection,
+            supported: true,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AddValidator<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> AddValidator<'info> {
+    pub fn execute(ctx: Context<AddValidator>, validator: [u8; 32]) -> Result<()> {
+        let validator_set = &mut ctx.accounts.validator_set;
+        if !ValidatorSet::is_valid_key(validator_set.scheme, &validator) {
+            return Err(BridgeErrors::InvalidSigner.into());
+        }
+        if validator_set.contains(&validator) {
+            return Err(BridgeErrors::ValidatorAlreadyExists.into());
+        }
+        if validator_set.validators.len() >= MAX_VALIDATORS {
+            return Err(BridgeErrors::TooManyValidators.into());
+        }
+        validator_set.validators.push(validator);
+        emit!(ValidatorAdded { validator });
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use crate::metadata::metadata_program;
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use solana_program::sysvar::instructions::ID as IX_ID;
+
+#[derive(Accounts)]
+#[instruction(
+    order_id: [u8; 32],
+    applicant: [u8; 32],
+    receipient: [u8; 32],
+    from_chain_id: [u8; 8],
+    to_chain_id: [u8; 8],
+)]
+pub struct ApplyNft<'info> {
+    #[account(seeds = [b"global-account-02"], bump)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    #[account(seeds = [b"nft-config"], bump)]
+    pub nft_config: Account<'info, NftConfig>,
+
+    #[account(seeds = [b"nft-collection", supported_collection.collection.as_ref()], bump)]
+    pub supported_collection: Account<'info, SupportedCollection>,
+
+    #[account(seeds = [b"chain", to_chain_id.as_ref()], bump)]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    #[account(constraint = mint.supply == 1 && mint.decimals == 0 @ BridgeErrors::InvalidNft)]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: read by `metadata::verified_collection`, address and owner checked here.
+    #[account(seeds = [b"metadata", metadata_program::ID.as_ref(), mint.key().as_ref()], bump, seeds::program = metadata_program::ID, owner = metadata_program::ID)]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account( init_if_needed, payer = user, seeds = [b"nft-escrow", mint.key().as_ref()], bump, token::mint = mint, token::authority = escrow )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account( init, payer = user, seeds = [b"apply-order", order_id.as_ref()], bump, space = OrderAccount::LEN )]
+    pub order: Account<'info, OrderAccount>,
+
+    #[account(mut, constraint = user_token_account.mint == mint.key())]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut, constraint = fee_token_account.mint == nft_config.fee_currency)]
+    pub fee_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut, constraint = treasury_token_account.owner == global_account.treasury @ BridgeErrors::InvalidTreasury, constraint = treasury_token_account.mint == nft_config.fee_currency)]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(address = IX_ID)]
+    pub ix_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ApplyNft<'info> {
+    /// Locks an NFT of a supported collection in its escrow and charges the
+    /// flat NFT fee, once validators signed the order.
+    pub fn execute(
+        ctx: Context<ApplyNft>,
+        order_id: [u8; 32],
+        applicant: [u8; 32],
+        receipient: [u8; 32],
+        from_chain_id: [u8; 8],
+        to_chain_id: [u8; 8],
+        deadline: [u8; 8],
+    ) -> Result<()> {
+        if ctx.accounts.global_account.applies_paused {
+            return Err(BridgeErrors::BridgePaused.into());
+        }
+        if !ctx.accounts.chain_config.enabled {
+            return Err(BridgeErrors::UnsupportedRoute.into());
+        }
+        if !ctx.accounts.supported_collection.enabled {
+            return Err(BridgeErrors::UnsupportedNft.into());
+        }
+        if Clock::get()?.unix_timestamp > i64::from_be_bytes(deadline) {
+            return Err(BridgeErrors::TransactionExpired.into());
+        }
+        let collection = ctx.accounts.supported_collection.collection;
+        let mint = ctx.accounts.mint.key();
+        let msg = crate::messages::apply_nft_message(
+            ctx.accounts.validator_set.scheme,
+            &ctx.accounts.global_account.message_domain(),
+            &order_id,
+            &applicant,
+            &receipient,
+            &from_chain_id,
+            &collection.to_bytes(),
+            &mint.to_bytes(),
+            &to_chain_id,
+            &deadline,
+        );
+        crate::utils::verify_validator_signatures(&ctx.accounts.ix_sysvar, &ctx.accounts.validator_set, &msg)?;
+        if ctx.accounts.user.key().to_bytes() != applicant {
+            return Err(BridgeErrors::InvalidAccess.into());
+        }
+        if u64::from_be_bytes(from_chain_id) != ctx.accounts.global_account.chain_id {
+            return Err(BridgeErrors::InvalidFromChainId.into());
+        }
+        if crate::metadata::verified_collection(&ctx.accounts.metadata, &mint) != Some(collection) {
+            return Err(BridgeErrors::UnsupportedNft.into());
+        }
+
+        let fee = ctx.accounts.nft_config.fee;
+        if fee > 0 {
+            token::transfer(ctx.accounts.transfer_fee_context(), fee)?;
+        }
+        token::transfer(ctx.accounts.transfer_nft_context(), 1)?;
+
+        let clock = Clock::get()?;
+        let order = &mut ctx.accounts.order;
+        order.order_id = order_id;
+        order.direction = OrderDirection::ApplyNft;
+        order.status = OrderStatus::Completed;
+        order.applicant = applicant;
+        order.receipient = receipient;
+        order.from_chain_id = u64::from_be_bytes(from_chain_id);
+        order.to_chain_id = u64::from_be_bytes(to_chain_id);
+        order.token = collection.to_bytes();
+        order.mint = mint;
+        order.amount = 1;
+        order.fee = fee;
+        order.slot = clock.slot;
+        order.timestamp = clock.unix_timestamp;
+
+        emit!(NftApplied {
+            order_id,
+            applicant,
+            receipient,
+            from_chain_id: order.from_chain_id,
+            to_chain_id: order.to_chain_id,
+            collection,
+            mint,
+            fee,
+            timestamp: order.timestamp,
+        });
+        Ok(())
+    }
+
+    fn transfer_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.fee_token_account.to_account_info(),
+            to: self.treasury_token_account.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn transfer_nft_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.user_token_account.to_account_info(),
+            to: self.escrow.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
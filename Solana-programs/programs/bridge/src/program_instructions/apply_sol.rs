@@ -0,0 +1,118 @@
+use crate::prelude::*;
+use crate::utils::TokenApply;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use solana_program::sysvar::instructions::ID as IX_ID;
+use spl_token::native_mint;
+
+#[derive(Accounts)]
+#[instruction(
+    order_id: [u8; 32],
+    applicant: [u8; 32],
+    receipient: [u8; 32],
+    from_chain_id: [u8; 8],
+    from_token: [u8; 32],
+    amount: [u8; 32],
+    to_chain_id: [u8; 8],
+)]
+pub struct ApplySol<'info> {
+    #[account(seeds = [b"global-account-02"], bump)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    #[account(mut, seeds = [b"vault", native_mint::ID.as_ref()], bump)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [b"token-config", native_mint::ID.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(seeds = [b"chain", to_chain_id.as_ref()], bump)]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    #[account(seeds = [b"route", native_mint::ID.as_ref(), to_chain_id.as_ref()], bump)]
+    pub route: Account<'info, TokenRoute>,
+
+    #[account( init, payer = user, seeds = [b"apply-order", order_id.as_ref()], bump, space = OrderAccount::LEN )]
+    pub order: Account<'info, OrderAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(address = IX_ID)]
+    pub ix_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ApplySol<'info> {
+    /// Same as `apply_token` for the native mint, but takes lamports from the
+    /// user's system account and wraps them into the wSOL vault.
+    pub fn execute(
+        ctx: Context<ApplySol>,
+        order_id: [u8; 32],
+        applicant: [u8; 32],
+        receipient: [u8; 32],
+        from_chain_id: [u8; 8],
+        from_token: [u8; 32],
+        amount: [u8; 32],
+        to_chain_id: [u8; 8],
+        deadline: [u8; 8],
+    ) -> Result<()> {
+        let apply = TokenApply {
+            order_id,
+            applicant,
+            receipient,
+            from_chain_id,
+            from_token,
+            amount,
+            to_chain_id,
+            deadline,
+        };
+        let global = &ctx.accounts.global_account;
+        apply.check_open(
+            global,
+            &ctx.accounts.token_config,
+            &ctx.accounts.chain_config,
+            &ctx.accounts.route,
+        )?;
+        let local_amount = crate::utils::from_canonical_amount(&amount, native_mint::DECIMALS)?;
+        ctx.accounts.token_config.check_apply_amount(local_amount)?;
+        apply.verify(&ctx.accounts.ix_sysvar, &ctx.accounts.validator_set, global, None)?;
+        apply.check_source(global, &ctx.accounts.user.key(), &native_mint::ID)?;
+
+        let breakdown = crate::utils::split_apply_fee(
+            global,
+            &ctx.accounts.token_config,
+            &ctx.accounts.chain_config,
+            &ctx.accounts.route,
+            local_amount,
+            native_mint::DECIMALS,
+        )?;
+        crate::utils::wrap_sol(
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program,
+            &ctx.accounts.user,
+            &ctx.accounts.vault,
+            breakdown.total,
+        )?;
+        ctx.accounts.token_config.accrue_fee(breakdown.fee)?;
+        apply.fill_order(&mut ctx.accounts.order, OrderDirection::ApplySol, native_mint::ID, &breakdown)?;
+
+        let order = &ctx.accounts.order;
+        emit!(TokenApplied {
+            order_id,
+            applicant,
+            receipient,
+            from_chain_id: order.from_chain_id,
+            to_chain_id: order.to_chain_id,
+            token: order.mint,
+            gross_amount: breakdown.total,
+            fee: breakdown.fee,
+            timestamp: order.timestamp,
+        });
+        Ok(())
+    }
+}
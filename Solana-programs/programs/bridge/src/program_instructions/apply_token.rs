@@ -1,30 +1,54 @@
 use crate::prelude::*;
+use crate::utils::TokenApply;
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use solana_program::instruction::Instruction;
-use solana_program::sysvar::instructions::{load_instruction_at_checked, ID as IX_ID};
-
-pub const UNIT: f64 = 1000000000.0;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use solana_program::sysvar::instructions::ID as IX_ID;
 
 #[derive(Accounts)]
+#[instruction(
+    order_id: [u8; 32],
+    applicant: [u8; 32],
+    receipient: [u8; 32],
+    from_chain_id: [u8; 8],
+    from_token: [u8; 32],
+    amount: [u8; 32],
+    to_chain_id: [u8; 8],
+)]
 pub struct ApplyToken<'info> {
-    #[account(mut)]
+    #[account(seeds = [b"global-account-02"], bump)]
     pub global_account: Account<'info, GlobalAccount>,
 
-    #[account(mut,  constraint = vault.mint== user_token_account.mint)]
+    #[account(seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    #[account(mut, seeds = [b"vault", vault.mint.as_ref()], bump, constraint = vault.mint == user_token_account.mint)]
     pub vault: Account<'info, TokenAccount>,
 
-    #[account(mut, constraint = user_account.owner == user.key())]
-    pub user_account: Account<'info, UserAccount>,
+    #[account(mut, seeds = [b"token-config", vault.mint.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(mut, address = vault.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(seeds = [b"chain", to_chain_id.as_ref()], bump)]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    #[account(seeds = [b"route", vault.mint.as_ref(), to_chain_id.as_ref()], bump)]
+    pub route: Account<'info, TokenRoute>,
+
+    #[account( init, payer = user, seeds = [b"apply-order", order_id.as_ref()], bump, space = OrderAccount::LEN )]
+    pub order: Account<'info, OrderAccount>,
 
     #[account(mut)]
     pub user_token_account: Account<'info, TokenAccount>,
 
+    #[account(mut)]
     pub user: Signer<'info>,
 
     #[account(address = IX_ID)]
     pub ix_sysvar: AccountInfo<'info>,
 
+    pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
 }
 
@@ -36,44 +60,70 @@ impl<'info> ApplyToken<'info> {
         receipient: [u8; 32],
         from_chain_id: [u8; 8],
         from_token: [u8; 32],
-        amount: [u8; 8],
+        amount: [u8; 32],
         to_chain_id: [u8; 8],
         deadline: [u8; 8],
-        signature: [u8; 64],
     ) -> Result<()> {
-        if Clock::get()?.unix_timestamp > i64::from_be_bytes(deadline) {
-            return Err(BridgeErrors::TransactionExpired.into());
-        }
-        let mut msg: Vec<u8> = Vec::new();
-        msg.extend_from_slice(&order_id);
-        msg.extend_from_slice(&applicant);
-        msg.extend_from_slice(&receipient);
-        msg.extend_from_slice(&from_chain_id);
-        msg.extend_from_slice(&from_token);
-        msg.extend_from_slice(&amount);
-        msg.extend_from_slice(&to_chain_id);
-        msg.extend_from_slice(&deadline);
-        let signer = ctx.accounts.global_account.signer;
-        let instruction: Instruction =
-            load_instruction_at_checked(0, &ctx.accounts.ix_sysvar)?;
-
-        crate::utils::verify_ed25519_ix(&instruction, &signer, &msg, &signature)?;
-        let apply_amount = u64::from_be_bytes(amount) as f64;
-        let fee_rate = ctx.accounts.global_account.token_fee_rate as f64;
-        let fee = apply_amount * (fee_rate / UNIT);
-        let total_amount = (apply_amount + fee) as u64;
-        token::transfer((&*ctx.accounts).into(), total_amount)?;
-        let user_account = &mut ctx.accounts.user_account;
-        if user_account.token_applies.iter().any(|order| order.order_id == order_id) {
-            return Err(BridgeErrors::DuplicatedOrderId.into());
-        }
+        let apply = TokenApply {
+            order_id,
+            applicant,
+            receipient,
+            from_chain_id,
+            from_token,
+            amount,
+            to_chain_id,
+            deadline,
+        };
+        let global = &ctx.accounts.global_account;
+        apply.check_open(
+            global,
+            &ctx.accounts.token_config,
+            &ctx.accounts.chain_config,
+            &ctx.accounts.route,
+        )?;
+        let decimals = ctx.accounts.mint.decimals;
+        let local_amount = crate::utils::from_canonical_amount(&amount, decimals)?;
+        ctx.accounts.token_config.check_apply_amount(local_amount)?;
+        apply.verify(
+            &ctx.accounts.ix_sysvar,
+            &ctx.accounts.validator_set,
+            global,
+            Some(local_amount),
+        )?;
+        apply.check_source(global, &ctx.accounts.user.key(), &ctx.accounts.user_token_account.mint)?;
 
-        if ctx.accounts.user.key().to_bytes() != applicant {
-            return Err(BridgeErrors::InvalidAccess.into());
+        let breakdown = crate::utils::split_apply_fee(
+            global,
+            &ctx.accounts.token_config,
+            &ctx.accounts.chain_config,
+            &ctx.accounts.route,
+            local_amount,
+            decimals,
+        )?;
+        match ctx.accounts.token_config.mode {
+            TokenMode::LockUnlock => token::transfer((&*ctx.accounts).into(), breakdown.total)?,
+            TokenMode::MintBurn => {
+                token::burn((&*ctx.accounts).into(), breakdown.net)?;
+                if breakdown.fee > 0 {
+                    token::transfer((&*ctx.accounts).into(), breakdown.fee)?;
+                }
+            }
         }
-        user_account.token_applies.push(TokenApply {
+        ctx.accounts.token_config.accrue_fee(breakdown.fee)?;
+        let mint = ctx.accounts.user_token_account.mint;
+        apply.fill_order(&mut ctx.accounts.order, OrderDirection::Apply, mint, &breakdown)?;
+
+        let order = &ctx.accounts.order;
+        emit!(TokenApplied {
             order_id,
-            amount,
+            applicant,
+            receipient,
+            from_chain_id: order.from_chain_id,
+            to_chain_id: order.to_chain_id,
+            token: order.mint,
+            gross_amount: breakdown.total,
+            fee: breakdown.fee,
+            timestamp: order.timestamp,
         });
         Ok(())
     }
@@ -91,3 +141,16 @@ impl<'info> From<&ApplyToken<'info>> for CpiContext<'_, '_, '_, 'info, Transfer<
         CpiContext::new(cpi_program, cpi_accounts)
     }
 }
+
+impl<'info> From<&ApplyToken<'info>> for CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+    fn from(accounts: &ApplyToken<'info>) -> Self {
+        let cpi_program = accounts.token_program.to_account_info();
+        let cpi_accounts = Burn {
+            mint: accounts.mint.to_account_info(),
+            to: accounts.user_token_account.to_account_info(),
+            authority: accounts.user.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelEmergencyWithdrawal<'info> {
+    #[account(seeds = [b"global-account-02"], bump)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"emergency-withdrawal", emergency_withdrawal.mint.as_ref()], bump, close = admin)]
+    pub emergency_withdrawal: Account<'info, EmergencyWithdrawal>,
+
+    /// CHECK: only receives the rent of the closed withdrawal account.
+    #[account(mut, address = global_account.admin)]
+    pub admin: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+impl<'info> CancelEmergencyWithdrawal<'info> {
+    pub fn execute(ctx: Context<CancelEmergencyWithdrawal>) -> Result<()> {
+        let withdrawal = &ctx.accounts.emergency_withdrawal;
+        let authority = ctx.accounts.authority.key();
+        if !ctx.accounts.global_account.is_guardian_or_admin(&authority) && authority != withdrawal.guardian {
+            return Err(BridgeErrors::InvalidAccess.into());
+        }
+        emit!(EmergencyWithdrawalCancelled {
+            token: withdrawal.mint,
+            destination: withdrawal.destination,
+            amount: withdrawal.amount,
+            cancelled_by: authority,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use crate::metadata::metadata_program;
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use solana_program::sysvar::instructions::ID as IX_ID;
+
+#[derive(Accounts)]
+#[instruction(
+    order_id: [u8; 32],
+    applicant: [u8; 32],
+    receipient: [u8; 32],
+    from_chain_id: [u8; 8],
+)]
+pub struct ClaimNft<'info> {
+    #[account(seeds = [b"global-account-02"], bump)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    #[account(seeds = [b"nft-collection", supported_collection.collection.as_ref()], bump)]
+    pub supported_collection: Account<'info, SupportedCollection>,
+
+    #[account(seeds = [b"chain", from_chain_id.as_ref()], bump)]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: read by `metadata::verified_collection`, address and owner checked here.
+    #[account(seeds = [b"metadata", metadata_program::ID.as_ref(), mint.key().as_ref()], bump, seeds::program = metadata_program::ID, owner = metadata_program::ID)]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [b"nft-escrow", mint.key().as_ref()], bump)]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account( init, payer = user, seeds = [b"claim-order", order_id.as_ref()], bump, space = OrderAccount::LEN )]
+    pub order: Account<'info, OrderAccount>,
+
+    #[account(mut, constraint = user_token_account.mint == mint.key())]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(address = IX_ID)]
+    pub ix_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimNft<'info> {
+    /// Releases an escrowed NFT to the recipient of a signed claim.
+    pub fn execute(
+        ctx: Context<ClaimNft>,
+        order_id: [u8; 32],
+        applicant: [u8; 32],
+        receipient: [u8; 32],
+        from_chain_id: [u8; 8],
+        to_chain_id: [u8; 8],
+        deadline: [u8; 8],
+    ) -> Result<()> {
+        if ctx.accounts.global_account.claims_paused {
+            return Err(BridgeErrors::BridgePaused.into());
+        }
+        if !ctx.accounts.chain_config.enabled {
+            return Err(BridgeErrors::UnsupportedRoute.into());
+        }
+        if !ctx.accounts.supported_collection.enabled {
+            return Err(BridgeErrors::UnsupportedNft.into());
+        }
+        if Clock::get()?.unix_timestamp > i64::from_be_bytes(deadline) {
+            return Err(BridgeErrors::TransactionExpired.into());
+        }
+        let collection = ctx.accounts.supported_collection.collection;
+        let mint = ctx.accounts.mint.key();
+        let msg = crate::messages::claim_nft_message(
+            ctx.accounts.validator_set.scheme,
+            &ctx.accounts.global_account.message_domain(),
+            &order_id,
+            &applicant,
+            &receipient,
+            &from_chain_id,
+            &to_chain_id,
+            &collection.to_bytes(),
+            &mint.to_bytes(),
+            &deadline,
+        );
+        crate::utils::verify_validator_signatures(&ctx.accounts.ix_sysvar, &ctx.accounts.validator_set, &msg)?;
+        if ctx.accounts.user.key().to_bytes() != receipient {
+            return Err(BridgeErrors::InvalidAccess.into());
+        }
+        if u64::from_be_bytes(to_chain_id) != ctx.accounts.global_account.chain_id {
+            return Err(BridgeErrors::InvalidToChainId.into());
+        }
+        if ctx.accounts.user_token_account.owner.to_bytes() != receipient {
+            return Err(BridgeErrors::InvalidReceipientTokenAccount.into());
+        }
+        if crate::metadata::verified_collection(&ctx.accounts.metadata, &mint) != Some(collection) {
+            return Err(BridgeErrors::UnsupportedNft.into());
+        }
+
+        crate::utils::release_nft(
+            &ctx.accounts.token_program,
+            &ctx.accounts.escrow,
+            &ctx.accounts.user_token_account,
+        )?;
+
+        let clock = Clock::get()?;
+        let order = &mut ctx.accounts.order;
+        order.order_id = order_id;
+        order.direction = OrderDirection::ClaimNft;
+        order.status = OrderStatus::Completed;
+        order.applicant = applicant;
+        order.receipient = receipient;
+        order.from_chain_id = u64::from_be_bytes(from_chain_id);
+        order.to_chain_id = u64::from_be_bytes(to_chain_id);
+        order.token = collection.to_bytes();
+        order.mint = mint;
+        order.amount = 1;
+        order.fee = 0;
+        order.slot = clock.slot;
+        order.timestamp = clock.unix_timestamp;
+
+        emit!(NftClaimed {
+            order_id,
+            applicant,
+            receipient,
+            to_chain_id: order.to_chain_id,
+            collection,
+            mint,
+            timestamp: order.timestamp,
+        });
+        Ok(())
+    }
+}
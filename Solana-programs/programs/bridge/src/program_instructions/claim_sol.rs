@@ -0,0 +1,116 @@
+use crate::prelude::*;
+use crate::utils::TokenClaim;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use solana_program::sysvar::instructions::ID as IX_ID;
+use spl_token::native_mint;
+
+#[derive(Accounts)]
+#[instruction(order_id: [u8; 32])]
+pub struct ClaimSol<'info> {
+    #[account(seeds = [b"global-account-02"], bump)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    #[account(mut, seeds = [b"vault", native_mint::ID.as_ref()], bump)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [b"token-config", native_mint::ID.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(address = native_mint::ID)]
+    pub mint: Account<'info, Mint>,
+
+    #[account( init, payer = user, seeds = [b"claim-order", order_id.as_ref()], bump, space = OrderAccount::LEN )]
+    pub order: Account<'info, OrderAccount>,
+
+    /// Holds the claimed wSOL for the duration of the instruction and is
+    /// closed into `user` before it returns.
+    #[account( init, payer = user, seeds = [b"unwrap", order_id.as_ref()], bump, token::mint = mint, token::authority = vault )]
+    pub unwrap_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(address = IX_ID)]
+    pub ix_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ClaimSol<'info> {
+    /// Same as `claim_token` for the native mint, but unwraps the claimed wSOL
+    /// so the recipient receives lamports on their system account.
+    pub fn execute(
+        ctx: Context<ClaimSol>,
+        order_id: [u8; 32],
+        applicant: [u8; 32],
+        receipient: [u8; 32],
+        from_chain_id: [u8; 8],
+        to_chain_id: [u8; 8],
+        to_token: [u8; 32],
+        amount: [u8; 32],
+        deadline: [u8; 8],
+    ) -> Result<()> {
+        let claim = TokenClaim {
+            order_id,
+            applicant,
+            receipient,
+            from_chain_id,
+            to_chain_id,
+            to_token,
+            amount,
+            deadline,
+        };
+        let global = &ctx.accounts.global_account;
+        claim.check_open(global, &ctx.accounts.token_config)?;
+        let claim_amount = crate::utils::from_canonical_amount(&amount, native_mint::DECIMALS)?;
+        let msg = claim.message(ctx.accounts.validator_set.scheme, &global.message_domain());
+        let layout = claim.verify(
+            &ctx.accounts.ix_sysvar,
+            &ctx.accounts.validator_set,
+            global,
+            &msg,
+            Some(claim_amount),
+        )?;
+        claim.check_destination(global, &ctx.accounts.user.key(), &native_mint::ID)?;
+        claim.fill_order(
+            &mut ctx.accounts.order,
+            layout,
+            OrderDirection::ClaimSol,
+            native_mint::ID,
+            claim_amount,
+            0,
+        )?;
+
+        let clock = Clock::get()?;
+        ctx.accounts.token_config.consume_outflow(claim_amount, clock.unix_timestamp)?;
+        let liquidity = ctx.accounts.vault.amount.saturating_sub(ctx.accounts.token_config.accrued_fees);
+        if claim_amount > liquidity {
+            return Err(BridgeErrors::InsufficientLiquidity.into());
+        }
+        crate::utils::unwrap_sol(
+            &ctx.accounts.token_program,
+            &ctx.accounts.vault,
+            &ctx.accounts.unwrap_account,
+            &ctx.accounts.user,
+            claim_amount,
+        )?;
+
+        emit!(TokenClaimed {
+            order_id,
+            applicant,
+            receipient,
+            to_chain_id: u64::from_be_bytes(to_chain_id),
+            token: native_mint::ID,
+            gross_amount: claim_amount,
+            fee: 0,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
+}
@@ -1,76 +1,115 @@
 use crate::prelude::*;
+use crate::utils::TokenClaim;
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Transfer};
-use solana_program::instruction::Instruction;
-use solana_program::sysvar::instructions::{load_instruction_at_checked, ID as IX_ID};
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
+use solana_program::sysvar::instructions::ID as IX_ID;
 
 #[derive(Accounts)]
+#[instruction(order_id: [u8; 32])]
 pub struct ClaimToken<'info> {
-    #[account(mut)]
+    #[account(seeds = [b"global-account-02"], bump)]
     pub global_account: Account<'info, GlobalAccount>,
 
-    #[account(mut,  constraint = vault.mint== user_token_account.mint)]
+    #[account(seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    #[account(mut, seeds = [b"vault", vault.mint.as_ref()], bump, constraint = vault.mint == user_token_account.mint)]
     pub vault: Account<'info, TokenAccount>,
 
-    #[account(mut, constraint = user_account.owner == user.key())]
-    pub user_account: Account<'info, UserAccount>,
+    #[account(mut, seeds = [b"token-config", vault.mint.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(mut, address = vault.mint)]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: only used as the mint authority signer of `MintBurn` tokens.
+    #[account(seeds = [b"mint-authority", vault.mint.as_ref()], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account( init, payer = user, seeds = [b"claim-order", order_id.as_ref()], bump, space = OrderAccount::LEN )]
+    pub order: Account<'info, OrderAccount>,
 
     #[account(mut)]
     pub user_token_account: Account<'info, TokenAccount>,
 
+    #[account(mut)]
     pub user: Signer<'info>,
 
     #[account(address = IX_ID)]
     pub ix_sysvar: AccountInfo<'info>,
 
+    pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
 }
 
 impl<'info> ClaimToken<'info> {
     pub fn execute(
+        ctx: Context<ClaimToken>,
         order_id: [u8; 32],
         applicant: [u8; 32],
         receipient: [u8; 32],
+        from_chain_id: [u8; 8],
         to_chain_id: [u8; 8],
         to_token: [u8; 32],
-        amount: [u8; 8],
+        amount: [u8; 32],
         deadline: [u8; 8],
-        signature: [u8; 64],
     ) -> Result<()> {
-        if Clock::get()?.unix_timestamp > i64::from_be_bytes(deadline) {
-            return Err(BridgeErrors::TransactionExpired.into());
-        }
-        let mut msg: Vec<u8> = Vec::new();
-        msg.extend_from_slice(&order_id);
-        msg.extend_from_slice(&applicant);
-        msg.extend_from_slice(&receipient);
-        msg.extend_from_slice(&to_chain_id);
-        msg.extend_from_slice(&to_token);
-        msg.extend_from_slice(&amount);
-        msg.extend_from_slice(&deadline);
-        let signer = ctx.accounts.global_account.signer;
-        let instruction: Instruction = load_instruction_at_checked(0, &ctx.accounts.ix_sysvar)?;
-        crate::utils::verify_ed25519_ix(&instruction, &signer, &msg, &signature)?;
-        let user_account = &mut ctx.accounts.user_account;
-        if user_account.token_claims.iter().any(|order| order.order_id == order_id) {
-            return Err(BridgeErrors::DuplicatedOrderId.into());
-        }
-
-        if ctx.accounts.user.key().to_bytes() != receipient {
-            return Err(BridgeErrors::InvalidAccess.into());
-        }
-
-        user_account.token_claims.push(TokenClaim {
+        let claim = TokenClaim {
             order_id,
+            applicant,
+            receipient,
+            from_chain_id,
+            to_chain_id,
+            to_token,
             amount,
-        });
+            deadline,
+        };
+        let global = &ctx.accounts.global_account;
+        claim.check_open(global, &ctx.accounts.token_config)?;
+        let claim_amount = crate::utils::from_canonical_amount(&amount, ctx.accounts.mint.decimals)?;
+        let msg = claim.message(ctx.accounts.validator_set.scheme, &global.message_domain());
+        let layout = claim.verify(
+            &ctx.accounts.ix_sysvar,
+            &ctx.accounts.validator_set,
+            global,
+            &msg,
+            Some(claim_amount),
+        )?;
+        claim.check_destination(global, &ctx.accounts.user.key(), &ctx.accounts.vault.mint)?;
+        if ctx.accounts.user_token_account.owner.to_bytes() != receipient {
+            return Err(BridgeErrors::InvalidReceipientTokenAccount.into());
+        }
+        claim.fill_order(
+            &mut ctx.accounts.order,
+            layout,
+            OrderDirection::Claim,
+            ctx.accounts.vault.mint,
+            claim_amount,
+            0,
+        )?;
 
-        crate::utils::transer_to_user(
+        let clock = Clock::get()?;
+        ctx.accounts.token_config.consume_outflow(claim_amount, clock.unix_timestamp)?;
+        crate::utils::release_tokens(
             &ctx.accounts.token_program,
+            &ctx.accounts.token_config,
             &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_authority,
             &ctx.accounts.user_token_account,
-            u64::from_be_bytes(amount),
+            claim_amount,
         )?;
+
+        emit!(TokenClaimed {
+            order_id,
+            applicant,
+            receipient,
+            to_chain_id: u64::from_be_bytes(to_chain_id),
+            token: ctx.accounts.vault.mint,
+            gross_amount: claim_amount,
+            fee: 0,
+            timestamp: clock.unix_timestamp,
+        });
         Ok(())
     }
 }
@@ -0,0 +1,198 @@
+use crate::prelude::*;
+use crate::utils::TokenClaim;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::keccak;
+use solana_program::program::invoke_signed;
+use solana_program::sysvar::instructions::ID as IX_ID;
+
+#[derive(Accounts)]
+#[instruction(order_id: [u8; 32])]
+pub struct ClaimTokenAndCall<'info> {
+    #[account(seeds = [b"global-account-02"], bump)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    #[account(mut, seeds = [b"vault", vault.mint.as_ref()], bump, constraint = vault.mint == user_token_account.mint)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [b"token-config", vault.mint.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(mut, address = vault.mint)]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: only used as the mint authority signer of `MintBurn` tokens.
+    #[account(seeds = [b"mint-authority", vault.mint.as_ref()], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account( init, payer = user, seeds = [b"claim-order", order_id.as_ref()], bump, space = OrderAccount::LEN )]
+    pub order: Account<'info, OrderAccount>,
+
+    /// CHECK: owns `call_token_account` and signs the call to the target. It is
+    /// derived per target and mint, so no target can sign with another's authority.
+    #[account(seeds = [b"call-authority", target_program.key().as_ref(), vault.mint.as_ref()], bump)]
+    pub call_authority: UncheckedAccount<'info>,
+
+    #[account( init_if_needed, payer = user, seeds = [b"call-account", target_program.key().as_ref(), vault.mint.as_ref()], bump, token::mint = mint, token::authority = call_authority )]
+    pub call_token_account: Account<'info, TokenAccount>,
+
+    #[account(seeds = [b"call-target", target_program.key().as_ref()], bump)]
+    pub call_target: Account<'info, CallTarget>,
+
+    /// CHECK: allowlisted through `call_target`.
+    #[account(constraint = target_program.executable @ BridgeErrors::InvalidCallTarget)]
+    pub target_program: UncheckedAccount<'info>,
+
+    /// Receives whatever the target leaves in `call_token_account`.
+    #[account(mut)]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(address = IX_ID)]
+    pub ix_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ClaimTokenAndCall<'info> {
+    /// Claims like `claim_token`, but pays into `call_token_account` and then
+    /// invokes the signed target program with the signed payload and the
+    /// remaining accounts, with `call_authority` as a signer so the target can
+    /// pull the tokens. Any error in the call reverts the whole claim.
+    pub fn execute(
+        ctx: Context<'_, '_, '_, 'info, ClaimTokenAndCall<'info>>,
+        order_id: [u8; 32],
+        applicant: [u8; 32],
+        receipient: [u8; 32],
+        from_chain_id: [u8; 8],
+        to_chain_id: [u8; 8],
+        to_token: [u8; 32],
+        amount: [u8; 32],
+        deadline: [u8; 8],
+        payload: Vec<u8>,
+    ) -> Result<()> {
+        let claim = TokenClaim {
+            order_id,
+            applicant,
+            receipient,
+            from_chain_id,
+            to_chain_id,
+            to_token,
+            amount,
+            deadline,
+        };
+        let global = &ctx.accounts.global_account;
+        claim.check_open(global, &ctx.accounts.token_config)?;
+        let target_program = ctx.accounts.target_program.key();
+        let claim_amount = crate::utils::from_canonical_amount(&amount, ctx.accounts.mint.decimals)?;
+        let msg = crate::messages::claim_and_call_message(
+            ctx.accounts.validator_set.scheme,
+            &global.message_domain(),
+            &order_id,
+            &applicant,
+            &receipient,
+            &from_chain_id,
+            &to_chain_id,
+            &to_token,
+            &amount,
+            &deadline,
+            &target_program.to_bytes(),
+            &payload,
+        );
+        let layout = claim.verify(&ctx.accounts.ix_sysvar, &ctx.accounts.validator_set, global, &msg, None)?;
+        claim.check_destination(global, &ctx.accounts.user.key(), &ctx.accounts.vault.mint)?;
+        if ctx.accounts.user_token_account.owner.to_bytes() != receipient {
+            return Err(BridgeErrors::InvalidReceipientTokenAccount.into());
+        }
+        claim.fill_order(
+            &mut ctx.accounts.order,
+            layout,
+            OrderDirection::Claim,
+            ctx.accounts.vault.mint,
+            claim_amount,
+            0,
+        )?;
+
+        let clock = Clock::get()?;
+        ctx.accounts.token_config.consume_outflow(claim_amount, clock.unix_timestamp)?;
+        crate::utils::release_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.token_config,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_authority,
+            &ctx.accounts.call_token_account,
+            claim_amount,
+        )?;
+
+        let payload_hash = keccak::hash(&payload).to_bytes();
+        let call_authority = ctx.accounts.call_authority.key();
+        let mint = ctx.accounts.vault.mint;
+        let authority_seeds: &[&[u8]] = &[
+            b"call-authority",
+            target_program.as_ref(),
+            mint.as_ref(),
+            &[ctx.bumps["call_authority"]],
+        ];
+        let call = Instruction {
+            program_id: target_program,
+            accounts: ctx
+                .remaining_accounts
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: account.key(),
+                    is_signer: account.is_signer || account.key() == call_authority,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: payload,
+        };
+        let mut call_accounts = ctx.remaining_accounts.to_vec();
+        call_accounts.push(ctx.accounts.call_authority.to_account_info());
+        call_accounts.push(ctx.accounts.target_program.to_account_info());
+        invoke_signed(&call, &call_accounts, &[authority_seeds])?;
+
+        ctx.accounts.call_token_account.reload()?;
+        let returned_amount = ctx.accounts.call_token_account.amount;
+        if returned_amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.call_token_account.to_account_info(),
+                        to: ctx.accounts.user_token_account.to_account_info(),
+                        authority: ctx.accounts.call_authority.to_account_info(),
+                    },
+                    &[authority_seeds],
+                ),
+                returned_amount,
+            )?;
+        }
+
+        emit!(TokenClaimed {
+            order_id,
+            applicant,
+            receipient,
+            to_chain_id: u64::from_be_bytes(to_chain_id),
+            token: ctx.accounts.vault.mint,
+            gross_amount: claim_amount,
+            fee: 0,
+            timestamp: clock.unix_timestamp,
+        });
+        emit!(TokenClaimedAndCalled {
+            order_id,
+            target_program,
+            payload_hash,
+            returned_amount,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use crate::prelude::*;
+use crate::utils::TokenClaim;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use solana_program::sysvar::instructions::ID as IX_ID;
+
+/// Permissionless variant of `ClaimToken`: any relayer submits the signed
+/// claim and pays for the accounts, the recipient does not need to sign.
+#[derive(Accounts)]
+#[instruction(order_id: [u8; 32])]
+pub struct DeliverToken<'info> {
+    #[account(seeds = [b"global-account-02"], bump)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    #[account(mut, seeds = [b"vault", vault.mint.as_ref()], bump)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [b"token-config", vault.mint.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(mut, address = vault.mint)]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: only used as the mint authority signer of `MintBurn` tokens.
+    #[account(seeds = [b"mint-authority", vault.mint.as_ref()], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account( init, payer = relayer, seeds = [b"claim-order", order_id.as_ref()], bump, space = OrderAccount::LEN )]
+    pub order: Account<'info, OrderAccount>,
+
+    /// CHECK: compared against the signed `receipient` in `execute`.
+    pub receipient_wallet: UncheckedAccount<'info>,
+
+    #[account( init_if_needed, payer = relayer, associated_token::mint = mint, associated_token::authority = receipient_wallet )]
+    pub receipient_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut, constraint = relayer_token_account.mint == vault.mint)]
+    pub relayer_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(address = IX_ID)]
+    pub ix_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> DeliverToken<'info> {
+    pub fn execute(
+        ctx: Context<DeliverToken>,
+        order_id: [u8; 32],
+        applicant: [u8; 32],
+        receipient: [u8; 32],
+        from_chain_id: [u8; 8],
+        to_chain_id: [u8; 8],
+        to_token: [u8; 32],
+        amount: [u8; 32],
+        deadline: [u8; 8],
+        relayer_fee: [u8; 32],
+    ) -> Result<()> {
+        let claim = TokenClaim {
+            order_id,
+            applicant,
+            receipient,
+            from_chain_id,
+            to_chain_id,
+            to_token,
+            amount,
+            deadline,
+        };
+        let global = &ctx.accounts.global_account;
+        claim.check_open(global, &ctx.accounts.token_config)?;
+        let decimals = ctx.accounts.mint.decimals;
+        let claim_amount = crate::utils::from_canonical_amount(&amount, decimals)?;
+        let msg = crate::messages::deliver_token_message(
+            ctx.accounts.validator_set.scheme,
+            &global.message_domain(),
+            &order_id,
+            &applicant,
+            &receipient,
+            &from_chain_id,
+            &to_chain_id,
+            &to_token,
+            &amount,
+            &deadline,
+            &relayer_fee,
+        );
+        let relayer_fee = crate::utils::from_canonical_amount(&relayer_fee, decimals)?;
+        // A legacy claim carries no relayer fee, so it can only be delivered for free.
+        let legacy_amount = Some(claim_amount).filter(|_| relayer_fee == 0);
+        let layout = claim.verify(
+            &ctx.accounts.ix_sysvar,
+            &ctx.accounts.validator_set,
+            global,
+            &msg,
+            legacy_amount,
+        )?;
+        claim.check_destination(global, &ctx.accounts.receipient_wallet.key(), &ctx.accounts.vault.mint)?;
+
+        let receipient_amount = claim_amount
+            .checked_sub(relayer_fee)
+            .ok_or(BridgeErrors::InvalidRelayerFee)?;
+        claim.fill_order(
+            &mut ctx.accounts.order,
+            layout,
+            OrderDirection::Claim,
+            ctx.accounts.vault.mint,
+            receipient_amount,
+            relayer_fee,
+        )?;
+
+        let clock = Clock::get()?;
+        ctx.accounts.token_config.consume_outflow(claim_amount, clock.unix_timestamp)?;
+        crate::utils::release_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.token_config,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_authority,
+            &ctx.accounts.receipient_token_account,
+            receipient_amount,
+        )?;
+        if relayer_fee > 0 {
+            ctx.accounts.vault.reload()?;
+            crate::utils::release_tokens(
+                &ctx.accounts.token_program,
+                &ctx.accounts.token_config,
+                &ctx.accounts.vault,
+                &ctx.accounts.mint,
+                &ctx.accounts.mint_authority,
+                &ctx.accounts.relayer_token_account,
+                relayer_fee,
+            )?;
+        }
+
+        emit!(TokenClaimed {
+            order_id,
+            applicant,
+            receipient,
+            to_chain_id: u64::from_be_bytes(to_chain_id),
+            token: ctx.accounts.vault.mint,
+            gross_amount: claim_amount,
+            fee: relayer_fee,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
+}
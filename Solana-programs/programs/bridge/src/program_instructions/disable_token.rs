@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct DisableToken<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"token-config", token_config.mint.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> DisableToken<'info> {
+    pub fn execute(ctx: Context<DisableToken>) -> Result<()> {
+        let token_config = &mut ctx.accounts.token_config;
+        token_config.enabled = false;
+        emit!(TokenUpdated {
+            token: token_config.mint,
+            enabled: false,
+            fee_rate_override: token_config.fee_rate_override,
+            min_amount: token_config.min_amount,
+            max_amount: token_config.max_amount,
+        });
+        Ok(())
+    }
+}
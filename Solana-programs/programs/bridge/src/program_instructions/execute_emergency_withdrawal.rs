@@ -0,0 +1,56 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ExecuteEmergencyWithdrawal<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"token-config", vault.mint.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [b"emergency-withdrawal", vault.mint.as_ref()], bump, close = admin)]
+    pub emergency_withdrawal: Account<'info, EmergencyWithdrawal>,
+
+    #[account(mut, address = emergency_withdrawal.destination @ BridgeErrors::InvalidWithdrawalDestination)]
+    pub destination: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ExecuteEmergencyWithdrawal<'info> {
+    pub fn execute(ctx: Context<ExecuteEmergencyWithdrawal>) -> Result<()> {
+        let withdrawal = &ctx.accounts.emergency_withdrawal;
+        if Clock::get()?.unix_timestamp < withdrawal.executable_at {
+            return Err(BridgeErrors::WithdrawalNotReady.into());
+        }
+        if withdrawal.amount > ctx.accounts.vault.amount {
+            return Err(BridgeErrors::InsufficientLiquidity.into());
+        }
+        let amount = withdrawal.amount;
+        crate::utils::transer_to_user(
+            &ctx.accounts.token_program,
+            &ctx.accounts.vault,
+            &ctx.accounts.destination,
+            amount,
+        )?;
+
+        // Fees can no longer be withdrawn beyond what is left in the vault.
+        ctx.accounts.vault.reload()?;
+        let token_config = &mut ctx.accounts.token_config;
+        token_config.accrued_fees = token_config.accrued_fees.min(ctx.accounts.vault.amount);
+        emit!(EmergencyWithdrawalExecuted {
+            token: ctx.accounts.vault.mint,
+            destination: ctx.accounts.destination.key(),
+            amount,
+        });
+        Ok(())
+    }
+}
@@ -1,23 +1,18 @@
 use crate::prelude::*;
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
-    #[account( init, payer = payer, seeds = [b"vault",token.key().as_ref()], bump, token::mint = token, token::authority = vault )]
-    pub vault: Account<'info, TokenAccount>,
-
-    #[account( init, payer = payer, seeds = [b"global-account-02"], bump, space = 100 )]
+    #[account( init, payer = payer, seeds = [b"global-account-02"], bump, space = GlobalAccount::LEN )]
     pub global_account: Account<'info, GlobalAccount>,
 
-    pub token: Account<'info, Mint>,
+    #[account( init, payer = payer, seeds = [b"validator-set"], bump, space = ValidatorSet::LEN )]
+    pub validator_set: Account<'info, ValidatorSet>,
 
     #[account(mut)]
     pub payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
 impl<'info> Initialize<'info> {
@@ -25,10 +20,30 @@ impl<'info> Initialize<'info> {
         ctx: Context<Initialize<'info>>,
         signer: [u8; 32],
         token_fee_rate: u64,
+        chain_id: u64,
     ) -> Result<()> {
+        if !ValidatorSet::is_valid_key(SignatureScheme::Ed25519, &signer) {
+            return Err(BridgeErrors::InvalidSigner.into());
+        }
+        if token_fee_rate > FEE_RATE_DENOMINATOR {
+            return Err(BridgeErrors::InvalidFeeRate.into());
+        }
         let global = &mut ctx.accounts.global_account;
-        global.signer = signer;
+        global.admin = ctx.accounts.payer.key();
+        global.pending_admin = Pubkey::default();
         global.token_fee_rate = token_fee_rate;
+        global.treasury = ctx.accounts.payer.key();
+        global.fee_mode = FeeMode::OnTop;
+        global.fee_rounding = FeeRounding::Up;
+        global.guardian = ctx.accounts.payer.key();
+        global.chain_id = chain_id;
+        global.legacy_messages_until = 0;
+        global.emergency_withdrawal_delay = MIN_EMERGENCY_WITHDRAWAL_DELAY;
+
+        let validator_set = &mut ctx.accounts.validator_set;
+        validator_set.scheme = SignatureScheme::Ed25519;
+        validator_set.threshold = 1;
+        validator_set.validators = vec![signer];
         Ok(())
     }
 }
@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct InitializeNftConfig<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account( init, payer = admin, seeds = [b"nft-config"], bump, space = NftConfig::LEN )]
+    pub nft_config: Account<'info, NftConfig>,
+
+    pub fee_currency: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeNftConfig<'info> {
+    pub fn execute(ctx: Context<InitializeNftConfig>, fee: u64) -> Result<()> {
+        let nft_config = &mut ctx.accounts.nft_config;
+        nft_config.fee_currency = ctx.accounts.fee_currency.key();
+        nft_config.fee = fee;
+        emit!(NftFeeUpdated {
+            fee_currency: nft_config.fee_currency,
+            fee,
+        });
+        Ok(())
+    }
+}
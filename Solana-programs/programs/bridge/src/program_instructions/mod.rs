@@ -1,4 +1,48 @@
+pub mod accept_admin;
+pub mod add_call_target;
+pub mod add_supported_collection;
+pub mod add_validator;
+pub mod apply_nft;
+pub mod apply_sol;
 pub mod apply_token;
+pub mod cancel_emergency_withdrawal;
+pub mod claim_nft;
+pub mod claim_sol;
 pub mod claim_token;
+pub mod claim_token_and_call;
+pub mod deliver_token;
+pub mod disable_token;
+pub mod execute_emergency_withdrawal;
 pub mod initialize;
-pub mod initialize_user_account;
+pub mod initialize_nft_config;
+pub mod pause;
+pub mod pause_token;
+pub mod refund_apply;
+pub mod refund_sol_apply;
+pub mod register_chain;
+pub mod register_route;
+pub mod register_token;
+pub mod remove_call_target;
+pub mod remove_supported_collection;
+pub mod remove_validator;
+pub mod schedule_emergency_withdrawal;
+pub mod set_token_mode;
+pub mod transfer_admin;
+pub mod unpause;
+pub mod unpause_token;
+pub mod update_chain;
+pub mod update_emergency_withdrawal_delay;
+pub mod update_fee_config;
+pub mod update_guardian;
+pub mod update_message_config;
+pub mod update_nft_fee;
+pub mod update_nft_fee_currency;
+pub mod update_rate_limit;
+pub mod update_route;
+pub mod update_signature_scheme;
+pub mod update_signer;
+pub mod update_threshold;
+pub mod update_token;
+pub mod update_token_fee_rate;
+pub mod update_treasury;
+pub mod withdraw_fees;
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    #[account(mut, seeds = [b"global-account-02"], bump, constraint = global_account.is_guardian_or_admin(&authority.key()) @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub authority: Signer<'info>,
+}
+
+impl<'info> Pause<'info> {
+    /// Pauses applies and/or claims for every token. Flags passed as `false`
+    /// are left untouched; only the admin can lift a pause.
+    pub fn execute(ctx: Context<Pause>, applies: bool, claims: bool) -> Result<()> {
+        let global = &mut ctx.accounts.global_account;
+        global.applies_paused |= applies;
+        global.claims_paused |= claims;
+        emit!(BridgePaused {
+            token: None,
+            applies,
+            claims,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct PauseToken<'info> {
+    #[account(seeds = [b"global-account-02"], bump, constraint = global_account.is_guardian_or_admin(&authority.key()) @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"token-config", token_config.mint.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+impl<'info> PauseToken<'info> {
+    pub fn execute(ctx: Context<PauseToken>, applies: bool, claims: bool) -> Result<()> {
+        let token_config = &mut ctx.accounts.token_config;
+        token_config.applies_paused |= applies;
+        token_config.claims_paused |= claims;
+        emit!(BridgePaused {
+            token: Some(token_config.mint),
+            applies,
+            claims,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use solana_program::sysvar::instructions::ID as IX_ID;
+
+#[derive(Accounts)]
+#[instruction(order_id: [u8; 32])]
+pub struct RefundApply<'info> {
+    #[account(seeds = [b"global-account-02"], bump)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    #[account(mut, seeds = [b"vault", vault.mint.as_ref()], bump, constraint = vault.mint == order.mint)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [b"token-config", vault.mint.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(mut, address = vault.mint)]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: only used as the mint authority signer of `MintBurn` tokens.
+    #[account(seeds = [b"mint-authority", vault.mint.as_ref()], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [b"apply-order", order_id.as_ref()], bump)]
+    pub order: Account<'info, OrderAccount>,
+
+    #[account(mut, constraint = applicant_token_account.owner.to_bytes() == order.applicant @ BridgeErrors::InvalidAccess, constraint = applicant_token_account.mint == order.mint)]
+    pub applicant_token_account: Account<'info, TokenAccount>,
+
+    pub payer: Signer<'info>,
+
+    #[account(address = IX_ID)]
+    pub ix_sysvar: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RefundApply<'info> {
+    /// Returns the funds locked by an apply order whose claim will never be
+    /// executed on the destination chain, once validators signed the refund.
+    pub fn execute(ctx: Context<RefundApply>, order_id: [u8; 32], refund_fee: bool, deadline: [u8; 8]) -> Result<()> {
+        if ctx.accounts.global_account.claims_paused || ctx.accounts.token_config.claims_paused {
+            return Err(BridgeErrors::BridgePaused.into());
+        }
+        if Clock::get()?.unix_timestamp > i64::from_be_bytes(deadline) {
+            return Err(BridgeErrors::TransactionExpired.into());
+        }
+        if ctx.accounts.order.direction != OrderDirection::Apply {
+            return Err(BridgeErrors::InvalidOrderDirection.into());
+        }
+        if ctx.accounts.order.status == OrderStatus::Refunded {
+            return Err(BridgeErrors::OrderAlreadyRefunded.into());
+        }
+        let applicant = ctx.accounts.order.applicant;
+        let msg = crate::messages::refund_apply_message(
+            ctx.accounts.validator_set.scheme,
+            &ctx.accounts.global_account.message_domain(),
+            &order_id,
+            &applicant,
+            refund_fee,
+            &deadline,
+        );
+        crate::utils::verify_validator_signatures(&ctx.accounts.ix_sysvar, &ctx.accounts.validator_set, &msg)?;
+
+        let amount = ctx.accounts.order.amount;
+        let fee = if refund_fee { ctx.accounts.order.fee } else { 0 };
+        ctx.accounts.token_config.consume_outflow(
+            amount.checked_add(fee).ok_or(BridgeErrors::MathOverflow)?,
+            Clock::get()?.unix_timestamp,
+        )?;
+        if fee > 0 {
+            let token_config = &mut ctx.accounts.token_config;
+            token_config.accrued_fees = token_config
+                .accrued_fees
+                .checked_sub(fee)
+                .ok_or(BridgeErrors::FeeNotRefundable)?;
+            crate::utils::transer_to_user(
+                &ctx.accounts.token_program,
+                &ctx.accounts.vault,
+                &ctx.accounts.applicant_token_account,
+                fee,
+            )?;
+            ctx.accounts.vault.reload()?;
+        }
+        crate::utils::release_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.token_config,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_authority,
+            &ctx.accounts.applicant_token_account,
+            amount,
+        )?;
+
+        ctx.accounts.order.status = OrderStatus::Refunded;
+        emit!(TokenRefunded {
+            order_id,
+            applicant,
+            token: ctx.accounts.vault.mint,
+            amount,
+            fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
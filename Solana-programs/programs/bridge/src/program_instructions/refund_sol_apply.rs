@@ -0,0 +1,112 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use solana_program::sysvar::instructions::ID as IX_ID;
+use spl_token::native_mint;
+
+#[derive(Accounts)]
+#[instruction(order_id: [u8; 32])]
+pub struct RefundSolApply<'info> {
+    #[account(seeds = [b"global-account-02"], bump)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    #[account(mut, seeds = [b"vault", native_mint::ID.as_ref()], bump)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [b"token-config", native_mint::ID.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(address = native_mint::ID)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut, seeds = [b"apply-order", order_id.as_ref()], bump)]
+    pub order: Account<'info, OrderAccount>,
+
+    /// Holds the refunded wSOL for the duration of the instruction and is
+    /// closed into `applicant` before it returns.
+    #[account( init, payer = payer, seeds = [b"unwrap", order_id.as_ref()], bump, token::mint = mint, token::authority = vault )]
+    pub unwrap_account: Account<'info, TokenAccount>,
+
+    /// CHECK: only receives lamports, its key must be the order applicant.
+    #[account(mut, constraint = applicant.key().to_bytes() == order.applicant @ BridgeErrors::InvalidAccess)]
+    pub applicant: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(address = IX_ID)]
+    pub ix_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> RefundSolApply<'info> {
+    /// `refund_apply` for orders created by `apply_sol`: the locked wSOL is
+    /// unwrapped and returned to the applicant as lamports.
+    pub fn execute(
+        ctx: Context<RefundSolApply>,
+        order_id: [u8; 32],
+        refund_fee: bool,
+        deadline: [u8; 8],
+    ) -> Result<()> {
+        if ctx.accounts.global_account.claims_paused || ctx.accounts.token_config.claims_paused {
+            return Err(BridgeErrors::BridgePaused.into());
+        }
+        if Clock::get()?.unix_timestamp > i64::from_be_bytes(deadline) {
+            return Err(BridgeErrors::TransactionExpired.into());
+        }
+        if ctx.accounts.order.direction != OrderDirection::ApplySol {
+            return Err(BridgeErrors::InvalidOrderDirection.into());
+        }
+        if ctx.accounts.order.status == OrderStatus::Refunded {
+            return Err(BridgeErrors::OrderAlreadyRefunded.into());
+        }
+        let applicant = ctx.accounts.order.applicant;
+        let msg = crate::messages::refund_apply_message(
+            ctx.accounts.validator_set.scheme,
+            &ctx.accounts.global_account.message_domain(),
+            &order_id,
+            &applicant,
+            refund_fee,
+            &deadline,
+        );
+        crate::utils::verify_validator_signatures(&ctx.accounts.ix_sysvar, &ctx.accounts.validator_set, &msg)?;
+
+        let amount = ctx.accounts.order.amount;
+        let fee = if refund_fee { ctx.accounts.order.fee } else { 0 };
+        let total = amount.checked_add(fee).ok_or(BridgeErrors::MathOverflow)?;
+        let token_config = &mut ctx.accounts.token_config;
+        token_config.consume_outflow(total, Clock::get()?.unix_timestamp)?;
+        token_config.accrued_fees = token_config
+            .accrued_fees
+            .checked_sub(fee)
+            .ok_or(BridgeErrors::FeeNotRefundable)?;
+        let liquidity = ctx.accounts.vault.amount.saturating_sub(token_config.accrued_fees);
+        if total > liquidity {
+            return Err(BridgeErrors::InsufficientLiquidity.into());
+        }
+        crate::utils::unwrap_sol(
+            &ctx.accounts.token_program,
+            &ctx.accounts.vault,
+            &ctx.accounts.unwrap_account,
+            &ctx.accounts.applicant,
+            total,
+        )?;
+
+        ctx.accounts.order.status = OrderStatus::Refunded;
+        emit!(TokenRefunded {
+            order_id,
+            applicant,
+            token: native_mint::ID,
+            amount,
+            fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
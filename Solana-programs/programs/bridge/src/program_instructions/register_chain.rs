@@ -0,0 +1,35 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(chain_id: [u8; 8])]
+pub struct RegisterChain<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account( init, payer = admin, seeds = [b"chain", chain_id.as_ref()], bump, space = ChainConfig::LEN )]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterChain<'info> {
+    pub fn execute(ctx: Context<RegisterChain>, chain_id: [u8; 8], fee_rate_override: Option<u64>) -> Result<()> {
+        if fee_rate_override.map_or(false, |rate| rate > FEE_RATE_DENOMINATOR) {
+            return Err(BridgeErrors::InvalidFeeRate.into());
+        }
+        let chain_config = &mut ctx.accounts.chain_config;
+        chain_config.chain_id = u64::from_be_bytes(chain_id);
+        chain_config.enabled = true;
+        chain_config.fee_rate_override = fee_rate_override;
+        emit!(ChainUpdated {
+            chain_id: chain_config.chain_id,
+            enabled: true,
+            fee_rate_override,
+        });
+        Ok(())
+    }
+}
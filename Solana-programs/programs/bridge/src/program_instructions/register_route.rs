@@ -0,0 +1,47 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+#[instruction(chain_id: [u8; 8])]
+pub struct RegisterRoute<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(seeds = [b"chain", chain_id.as_ref()], bump)]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    #[account( init, payer = admin, seeds = [b"route", mint.key().as_ref(), chain_id.as_ref()], bump, space = TokenRoute::LEN )]
+    pub route: Account<'info, TokenRoute>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterRoute<'info> {
+    pub fn execute(
+        ctx: Context<RegisterRoute>,
+        chain_id: [u8; 8],
+        remote_token: [u8; 32],
+        remote_decimals: u8,
+    ) -> Result<()> {
+        let route = &mut ctx.accounts.route;
+        route.mint = ctx.accounts.mint.key();
+        route.chain_id = u64::from_be_bytes(chain_id);
+        route.remote_token = remote_token;
+        route.remote_decimals = remote_decimals;
+        route.enabled = true;
+        emit!(RouteUpdated {
+            token: route.mint,
+            chain_id: route.chain_id,
+            remote_token,
+            remote_decimals,
+            enabled: true,
+        });
+        Ok(())
+    }
+}
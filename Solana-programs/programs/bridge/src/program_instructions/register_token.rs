@@ -0,0 +1,59 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct RegisterToken<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account( init, payer = admin, seeds = [b"vault", mint.key().as_ref()], bump, token::mint = mint, token::authority = vault )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account( init, payer = admin, seeds = [b"token-config", mint.key().as_ref()], bump, space = TokenConfig::LEN )]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account( init_if_needed, payer = admin, seeds = [b"token-registry"], bump, space = TokenRegistry::LEN )]
+    pub token_registry: Account<'info, TokenRegistry>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> RegisterToken<'info> {
+    pub fn execute(
+        ctx: Context<RegisterToken>,
+        fee_rate_override: Option<u64>,
+        min_amount: u64,
+        max_amount: u64,
+    ) -> Result<()> {
+        crate::utils::check_token_settings(fee_rate_override, min_amount, max_amount)?;
+        let registry = &mut ctx.accounts.token_registry;
+        if registry.tokens.len() >= MAX_TOKENS {
+            return Err(BridgeErrors::TooManyTokens.into());
+        }
+        registry.tokens.push(ctx.accounts.mint.key());
+
+        let token_config = &mut ctx.accounts.token_config;
+        token_config.mint = ctx.accounts.mint.key();
+        token_config.mode = TokenMode::LockUnlock;
+        token_config.enabled = true;
+        token_config.fee_rate_override = fee_rate_override;
+        token_config.min_amount = min_amount;
+        token_config.max_amount = max_amount;
+        emit!(TokenUpdated {
+            token: token_config.mint,
+            enabled: true,
+            fee_rate_override,
+            min_amount,
+            max_amount,
+        });
+        Ok(())
+    }
+}
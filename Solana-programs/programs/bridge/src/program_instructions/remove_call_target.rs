@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveCallTarget<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"call-target", call_target.program_id.as_ref()], bump, close = admin)]
+    pub call_target: Account<'info, CallTarget>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> RemoveCallTarget<'info> {
+    pub fn execute(ctx: Context<RemoveCallTarget>) -> Result<()> {
+        emit!(CallTargetUpdated {
+            program_id: ctx.accounts.call_target.program_id,
+            allowed: false,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveSupportedCollection<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"nft-collection", supported_collection.collection.as_ref()], bump)]
+    pub supported_collection: Account<'info, SupportedCollection>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> RemoveSupportedCollection<'info> {
+    pub fn execute(ctx: Context<RemoveSupportedCollection>) -> Result<()> {
+        ctx.accounts.supported_collection.enabled = false;
+        emit!(SupportedCollectionUpdated {
+            collection: ctx.accounts.supported_collection.collection,
+            supported: false,
+        });
+        Ok(())
+    }
+}
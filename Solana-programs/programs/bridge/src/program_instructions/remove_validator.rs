@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveValidator<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> RemoveValidator<'info> {
+    pub fn execute(ctx: Context<RemoveValidator>, validator: [u8; 32]) -> Result<()> {
+        let validator_set = &mut ctx.accounts.validator_set;
+        let index = validator_set
+            .validators
+            .iter()
+            .position(|item| item == &validator)
+            .ok_or(BridgeErrors::ValidatorNotFound)?;
+        if validator_set.validators.len() - 1 < validator_set.threshold as usize {
+            return Err(BridgeErrors::InvalidThreshold.into());
+        }
+        validator_set.validators.remove(index);
+        emit!(ValidatorRemoved { validator });
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+pub struct ScheduleEmergencyWithdrawal<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(seeds = [b"vault", vault.mint.as_ref()], bump)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account( init, payer = admin, seeds = [b"emergency-withdrawal", vault.mint.as_ref()], bump, space = EmergencyWithdrawal::LEN )]
+    pub emergency_withdrawal: Account<'info, EmergencyWithdrawal>,
+
+    #[account(constraint = destination.mint == vault.mint)]
+    pub destination: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ScheduleEmergencyWithdrawal<'info> {
+    /// Starts the timelock for moving `amount` out of the vault. The current
+    /// guardian can cancel it until the admin executes it, and so can the
+    /// guardian recorded here, so replacing the guardian does not remove the
+    /// veto on a pending withdrawal. Only fungible token vaults are covered;
+    /// NFTs held in `nft-escrow` accounts cannot be withdrawn this way.
+    pub fn execute(ctx: Context<ScheduleEmergencyWithdrawal>, amount: u64) -> Result<()> {
+        if amount == 0 || amount > ctx.accounts.vault.amount {
+            return Err(BridgeErrors::InsufficientLiquidity.into());
+        }
+        let now = Clock::get()?.unix_timestamp;
+        let executable_at = now
+            .checked_add(ctx.accounts.global_account.emergency_withdrawal_delay)
+            .ok_or(BridgeErrors::MathOverflow)?;
+
+        let withdrawal = &mut ctx.accounts.emergency_withdrawal;
+        withdrawal.mint = ctx.accounts.vault.mint;
+        withdrawal.amount = amount;
+        withdrawal.destination = ctx.accounts.destination.key();
+        withdrawal.scheduled_at = now;
+        withdrawal.executable_at = executable_at;
+        withdrawal.guardian = ctx.accounts.global_account.guardian;
+        emit!(EmergencyWithdrawalScheduled {
+            token: withdrawal.mint,
+            destination: withdrawal.destination,
+            amount,
+            executable_at,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{Mint, TokenAccount};
+
+#[derive(Accounts)]
+pub struct SetTokenMode<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"token-config", mint.key().as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(seeds = [b"vault", mint.key().as_ref()], bump)]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> SetTokenMode<'info> {
+    /// Switching modes changes how the vault balance is backed, so it is only
+    /// allowed while the vault holds neither bridged tokens nor unwithdrawn fees.
+    pub fn execute(ctx: Context<SetTokenMode>, mode: TokenMode) -> Result<()> {
+        let token_config = &ctx.accounts.token_config;
+        if mode != token_config.mode && (ctx.accounts.vault.amount > 0 || token_config.accrued_fees > 0) {
+            return Err(BridgeErrors::VaultNotEmpty.into());
+        }
+        if mode == TokenMode::MintBurn {
+            let (mint_authority, _) =
+                Pubkey::find_program_address(&[b"mint-authority", ctx.accounts.mint.key().as_ref()], &crate::id());
+            if ctx.accounts.mint.mint_authority != COption::Some(mint_authority) {
+                return Err(BridgeErrors::InvalidMintAuthority.into());
+            }
+        }
+        ctx.accounts.token_config.mode = mode;
+        emit!(TokenModeUpdated {
+            token: ctx.accounts.mint.key(),
+            mode,
+        });
+        Ok(())
+    }
+}
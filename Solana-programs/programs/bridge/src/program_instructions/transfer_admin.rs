@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct TransferAdmin<'info> {
+    #[account(mut, seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> TransferAdmin<'info> {
+    /// Proposes `new_admin`; the transfer only takes effect once `accept_admin`
+    /// is signed by it. Proposing `Pubkey::default()` cancels a pending transfer.
+    pub fn execute(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.global_account.pending_admin = new_admin;
+        emit!(AdminTransferProposed {
+            admin: ctx.accounts.admin.key(),
+            pending_admin: new_admin,
+        });
+        Ok(())
+    }
+}
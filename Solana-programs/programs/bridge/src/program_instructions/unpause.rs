@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    #[account(mut, seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> Unpause<'info> {
+    pub fn execute(ctx: Context<Unpause>, applies: bool, claims: bool) -> Result<()> {
+        let global = &mut ctx.accounts.global_account;
+        global.applies_paused &= !applies;
+        global.claims_paused &= !claims;
+        emit!(BridgeUnpaused {
+            token: None,
+            applies,
+            claims,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UnpauseToken<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"token-config", token_config.mint.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UnpauseToken<'info> {
+    pub fn execute(ctx: Context<UnpauseToken>, applies: bool, claims: bool) -> Result<()> {
+        let token_config = &mut ctx.accounts.token_config;
+        token_config.applies_paused &= !applies;
+        token_config.claims_paused &= !claims;
+        emit!(BridgeUnpaused {
+            token: Some(token_config.mint),
+            applies,
+            claims,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateChain<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"chain", chain_config.chain_id.to_be_bytes().as_ref()], bump)]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateChain<'info> {
+    pub fn execute(ctx: Context<UpdateChain>, enabled: bool, fee_rate_override: Option<u64>) -> Result<()> {
+        if fee_rate_override.map_or(false, |rate| rate > FEE_RATE_DENOMINATOR) {
+            return Err(BridgeErrors::InvalidFeeRate.into());
+        }
+        let chain_config = &mut ctx.accounts.chain_config;
+        chain_config.enabled = enabled;
+        chain_config.fee_rate_override = fee_rate_override;
+        emit!(ChainUpdated {
+            chain_id: chain_config.chain_id,
+            enabled,
+            fee_rate_override,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateEmergencyWithdrawalDelay<'info> {
+    #[account(mut, seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateEmergencyWithdrawalDelay<'info> {
+    /// Only affects withdrawals scheduled afterwards.
+    pub fn execute(ctx: Context<UpdateEmergencyWithdrawalDelay>, delay: i64) -> Result<()> {
+        if delay < MIN_EMERGENCY_WITHDRAWAL_DELAY {
+            return Err(BridgeErrors::InvalidWithdrawalDelay.into());
+        }
+        ctx.accounts.global_account.emergency_withdrawal_delay = delay;
+        emit!(EmergencyWithdrawalDelayUpdated { delay });
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateFeeConfig<'info> {
+    #[account(mut, seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateFeeConfig<'info> {
+    pub fn execute(ctx: Context<UpdateFeeConfig>, fee_mode: FeeMode, fee_rounding: FeeRounding) -> Result<()> {
+        let global = &mut ctx.accounts.global_account;
+        global.fee_mode = fee_mode;
+        global.fee_rounding = fee_rounding;
+        emit!(FeeConfigUpdated {
+            fee_mode,
+            fee_rounding,
+        });
+        Ok(())
+    }
+}
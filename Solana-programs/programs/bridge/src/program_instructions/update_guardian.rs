@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateGuardian<'info> {
+    #[account(mut, seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateGuardian<'info> {
+    pub fn execute(ctx: Context<UpdateGuardian>, guardian: Pubkey) -> Result<()> {
+        ctx.accounts.global_account.guardian = guardian;
+        emit!(GuardianUpdated { guardian });
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateMessageConfig<'info> {
+    #[account(mut, seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateMessageConfig<'info> {
+    /// Sets the local chain id bound into signed messages, and the end of the
+    /// window during which the legacy message layout is still accepted.
+    pub fn execute(ctx: Context<UpdateMessageConfig>, chain_id: u64, legacy_messages_until: i64) -> Result<()> {
+        let global = &mut ctx.accounts.global_account;
+        global.chain_id = chain_id;
+        global.legacy_messages_until = legacy_messages_until;
+        emit!(MessageConfigUpdated {
+            chain_id,
+            legacy_messages_until,
+        });
+        Ok(())
+    }
+}
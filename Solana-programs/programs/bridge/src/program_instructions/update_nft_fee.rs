@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateNftFee<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"nft-config"], bump)]
+    pub nft_config: Account<'info, NftConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateNftFee<'info> {
+    pub fn execute(ctx: Context<UpdateNftFee>, fee: u64) -> Result<()> {
+        let nft_config = &mut ctx.accounts.nft_config;
+        nft_config.fee = fee;
+        emit!(NftFeeUpdated {
+            fee_currency: nft_config.fee_currency,
+            fee,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct UpdateNftFeeCurrency<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"nft-config"], bump)]
+    pub nft_config: Account<'info, NftConfig>,
+
+    pub fee_currency: Account<'info, Mint>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateNftFeeCurrency<'info> {
+    pub fn execute(ctx: Context<UpdateNftFeeCurrency>) -> Result<()> {
+        let nft_config = &mut ctx.accounts.nft_config;
+        nft_config.fee_currency = ctx.accounts.fee_currency.key();
+        emit!(NftFeeUpdated {
+            fee_currency: nft_config.fee_currency,
+            fee: nft_config.fee,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateRateLimit<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"token-config", token_config.mint.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateRateLimit<'info> {
+    pub fn execute(
+        ctx: Context<UpdateRateLimit>,
+        max_claim_amount: u64,
+        window_limit: u64,
+        window_duration: i64,
+    ) -> Result<()> {
+        if window_limit > 0 && window_duration <= 0 {
+            return Err(BridgeErrors::InvalidRateLimit.into());
+        }
+        let token_config = &mut ctx.accounts.token_config;
+        token_config.max_claim_amount = max_claim_amount;
+        token_config.window_limit = window_limit;
+        token_config.window_duration = window_duration;
+        token_config.window_claimed = token_config.window_claimed.min(window_limit);
+        token_config.window_updated_at = Clock::get()?.unix_timestamp;
+        emit!(RateLimitUpdated {
+            token: token_config.mint,
+            max_claim_amount,
+            window_limit,
+            window_duration,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateRoute<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"route", route.mint.as_ref(), route.chain_id.to_be_bytes().as_ref()], bump)]
+    pub route: Account<'info, TokenRoute>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateRoute<'info> {
+    pub fn execute(
+        ctx: Context<UpdateRoute>,
+        remote_token: [u8; 32],
+        remote_decimals: u8,
+        enabled: bool,
+    ) -> Result<()> {
+        let route = &mut ctx.accounts.route;
+        route.remote_token = remote_token;
+        route.remote_decimals = remote_decimals;
+        route.enabled = enabled;
+        emit!(RouteUpdated {
+            token: route.mint,
+            chain_id: route.chain_id,
+            remote_token,
+            remote_decimals,
+            enabled,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateSignatureScheme<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateSignatureScheme<'info> {
+    /// Validator keys only make sense for one scheme, so switching schemes
+    /// replaces the whole validator set at once.
+    pub fn execute(
+        ctx: Context<UpdateSignatureScheme>,
+        scheme: SignatureScheme,
+        validators: Vec<[u8; 32]>,
+        threshold: u8,
+    ) -> Result<()> {
+        if validators.len() > MAX_VALIDATORS {
+            return Err(BridgeErrors::TooManyValidators.into());
+        }
+        for (index, validator) in validators.iter().enumerate() {
+            if !ValidatorSet::is_valid_key(scheme, validator) {
+                return Err(BridgeErrors::InvalidSigner.into());
+            }
+            if validators[..index].contains(validator) {
+                return Err(BridgeErrors::ValidatorAlreadyExists.into());
+            }
+        }
+        if threshold == 0 || threshold as usize > validators.len() {
+            return Err(BridgeErrors::InvalidThreshold.into());
+        }
+
+        let validator_set = &mut ctx.accounts.validator_set;
+        validator_set.scheme = scheme;
+        validator_set.threshold = threshold;
+        validator_set.validators = validators;
+        emit!(SignatureSchemeUpdated {
+            scheme,
+            threshold,
+            validators: validator_set.validators.clone(),
+        });
+        Ok(())
+    }
+}
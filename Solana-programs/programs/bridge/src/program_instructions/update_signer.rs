@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateSigner<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateSigner<'info> {
+    /// Deprecated single-signer entry point kept for existing clients until it
+    /// is removed. Replaces the validator set with `signer` alone, as an
+    /// Ed25519 key with a threshold of 1; use `update_signature_scheme` instead.
+    pub fn execute(ctx: Context<UpdateSigner>, signer: [u8; 32]) -> Result<()> {
+        if !ValidatorSet::is_valid_key(SignatureScheme::Ed25519, &signer) {
+            return Err(BridgeErrors::InvalidSigner.into());
+        }
+        let validator_set = &mut ctx.accounts.validator_set;
+        validator_set.scheme = SignatureScheme::Ed25519;
+        validator_set.threshold = 1;
+        validator_set.validators = vec![signer];
+        emit!(SignatureSchemeUpdated {
+            scheme: SignatureScheme::Ed25519,
+            threshold: 1,
+            validators: validator_set.validators.clone(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateThreshold<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"validator-set"], bump)]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateThreshold<'info> {
+    pub fn execute(ctx: Context<UpdateThreshold>, threshold: u8) -> Result<()> {
+        let validator_set = &mut ctx.accounts.validator_set;
+        if threshold == 0 || threshold as usize > validator_set.validators.len() {
+            return Err(BridgeErrors::InvalidThreshold.into());
+        }
+        validator_set.threshold = threshold;
+        emit!(ThresholdUpdated { threshold });
+        Ok(())
+    }
+}
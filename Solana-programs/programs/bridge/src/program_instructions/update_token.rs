@@ -0,0 +1,38 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateToken<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"token-config", token_config.mint.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateToken<'info> {
+    pub fn execute(
+        ctx: Context<UpdateToken>,
+        enabled: bool,
+        fee_rate_override: Option<u64>,
+        min_amount: u64,
+        max_amount: u64,
+    ) -> Result<()> {
+        crate::utils::check_token_settings(fee_rate_override, min_amount, max_amount)?;
+        let token_config = &mut ctx.accounts.token_config;
+        token_config.enabled = enabled;
+        token_config.fee_rate_override = fee_rate_override;
+        token_config.min_amount = min_amount;
+        token_config.max_amount = max_amount;
+        emit!(TokenUpdated {
+            token: token_config.mint,
+            enabled,
+            fee_rate_override,
+            min_amount,
+            max_amount,
+        });
+        Ok(())
+    }
+}
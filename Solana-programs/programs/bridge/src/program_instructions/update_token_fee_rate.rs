@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateTokenFeeRate<'info> {
+    #[account(mut, seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> UpdateTokenFeeRate<'info> {
+    pub fn execute(ctx: Context<UpdateTokenFeeRate>, token_fee_rate: u64) -> Result<()> {
+        if token_fee_rate > FEE_RATE_DENOMINATOR {
+            return Err(BridgeErrors::InvalidFeeRate.into());
+        }
+        ctx.accounts.global_account.token_fee_rate = token_fee_rate;
+        emit!(TokenFeeRateUpdated { token_fee_rate });
+        Ok(())
+    }
+}
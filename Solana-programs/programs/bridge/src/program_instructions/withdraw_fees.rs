@@ -0,0 +1,44 @@
+use crate::prelude::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(seeds = [b"global-account-02"], bump, has_one = admin @ BridgeErrors::InvalidAccess)]
+    pub global_account: Account<'info, GlobalAccount>,
+
+    #[account(mut, seeds = [b"token-config", vault.mint.as_ref()], bump)]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut, constraint = treasury_token_account.owner == global_account.treasury @ BridgeErrors::InvalidTreasury, constraint = treasury_token_account.mint == vault.mint)]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawFees<'info> {
+    pub fn execute(ctx: Context<WithdrawFees>) -> Result<()> {
+        let amount = ctx.accounts.token_config.accrued_fees;
+        if amount == 0 {
+            return Err(BridgeErrors::NoFeesToWithdraw.into());
+        }
+        crate::utils::transer_to_user(
+            &ctx.accounts.token_program,
+            &ctx.accounts.vault,
+            &ctx.accounts.treasury_token_account,
+            amount,
+        )?;
+        ctx.accounts.token_config.accrued_fees = 0;
+        emit!(FeesWithdrawn {
+            token: ctx.accounts.vault.mint,
+            treasury_token_account: ctx.accounts.treasury_token_account.key(),
+            amount,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+//! Helpers shared by the unit tests.
+use crate::errors::BridgeErrors;
+use anchor_lang::prelude::*;
+use solana_program::program_error::ProgramError;
+
+pub fn assert_error<T: std::fmt::Debug>(result: Result<T>, expected: BridgeErrors) {
+    assert_eq!(
+        ProgramError::from(result.unwrap_err()),
+        ProgramError::Custom(u32::from(expected))
+    );
+}
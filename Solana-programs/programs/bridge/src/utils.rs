@@ -1,47 +1,606 @@
 use crate::errors::BridgeErrors;
+use crate::messages::{Domain, Layout};
+use crate::program_accounts::{
+    ChainConfig, FeeMode, FeeRounding, GlobalAccount, OrderAccount, OrderDirection, OrderStatus, SignatureScheme,
+    TokenConfig, TokenMode, TokenRoute, ValidatorSet, FEE_RATE_DENOMINATOR,
+};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, TokenAccount, Transfer};
+use anchor_spl::token::{self, CloseAccount, Mint, MintTo, TokenAccount, Transfer};
 use solana_program::ed25519_program::ID as ED25519_ID;
-use solana_program::instruction::Instruction;
-use std::convert::TryInto;
-pub fn verify_ed25519_ix(ix: &Instruction, pubkey: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
-            ix.data.len()       != (16 + 64 + 32 + msg.len())
-    {
-    }
+use solana_program::program::invoke;
+use solana_program::secp256k1_program::ID as SECP256K1_ID;
+use solana_program::system_instruction;
+use solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+use std::convert::{TryFrom, TryInto};
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+const PUBKEY_SIZE: usize = 32;
+
+const SECP256K1_OFFSETS_START: usize = 1;
+const SECP256K1_OFFSETS_SERIALIZED_SIZE: usize = 11;
+const ETH_ADDRESS_SIZE: usize = 20;
 
+/// Collects the distinct validators that signed `msg` through the signature
+/// verification instructions (Ed25519 or Secp256k1, following the validator set
+/// scheme) found anywhere in the transaction, and requires at least `threshold`
+/// of them. The runtime verifies every such instruction before any program runs,
+/// so their position relative to the current instruction does not matter.
+pub fn verify_validator_signatures(
+    ix_sysvar: &AccountInfo,
+    validator_set: &ValidatorSet,
+    msg: &[u8],
+) -> Result<()> {
+    let num_instructions = {
+        let data = ix_sysvar.try_borrow_data()?;
+        if data.len() < 2 {
+            return Err(BridgeErrors::SignatureVerificationFailed.into());
+        }
+        u16::from_le_bytes([data[0], data[1]]) as usize
+    };
+    let current_index = load_current_index_checked(ix_sysvar)? as usize;
+    let load_instruction_data = |index: usize| -> Result<Vec<u8>> {
+        Ok(load_instruction_at_checked(index, ix_sysvar)?.data)
+    };
+
+    let mut signers: Vec<[u8; 32]> = Vec::new();
+    for index in (0..num_instructions).filter(|index| *index != current_index) {
+        let ix = load_instruction_at_checked(index, ix_sysvar)?;
+        if !ix.accounts.is_empty() {
+            continue;
+        }
+        let pubkeys = match validator_set.scheme {
+            SignatureScheme::Ed25519 if ix.program_id == ED25519_ID => {
+                parse_ed25519_signers(&ix.data, msg, &load_instruction_data)?
+            }
+            SignatureScheme::Secp256k1 if ix.program_id == SECP256K1_ID => {
+                parse_secp256k1_signers(&ix.data, index, msg, &load_instruction_data)?
+            }
+            _ => continue,
+        };
+        for pubkey in pubkeys {
+            if validator_set.contains(&pubkey) && !signers.contains(&pubkey) {
+                signers.push(pubkey);
+            }
+        }
+    }
 
+    if signers.len() < validator_set.threshold as usize {
+        return Err(BridgeErrors::InsufficientSignatures.into());
+    }
     Ok(())
 }
 
-pub fn check_ed25519_data(data: &[u8], pubkey: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+/// Like `verify_validator_signatures`, falling back to `legacy_msg` when the
+/// validators did not sign `msg` and the legacy layout is still accepted.
+/// Returns the layout that was signed.
+pub fn verify_order_signatures(
+    ix_sysvar: &AccountInfo,
+    validator_set: &ValidatorSet,
+    msg: &[u8],
+    legacy_msg: Option<&[u8]>,
+) -> Result<Layout> {
+    match (verify_validator_signatures(ix_sysvar, validator_set, msg), legacy_msg) {
+        (Err(_), Some(legacy_msg)) => {
+            verify_validator_signatures(ix_sysvar, validator_set, legacy_msg).map(|_| Layout::Legacy)
+        }
+        (result, _) => result.map(|_| Layout::Versioned),
+    }
+}
+
+/// The fields validators sign for a token apply, shared by `apply_token` and
+/// `apply_sol`.
+pub struct TokenApply {
+    pub order_id: [u8; 32],
+    pub applicant: [u8; 32],
+    pub receipient: [u8; 32],
+    pub from_chain_id: [u8; 8],
+    pub from_token: [u8; 32],
+    pub amount: [u8; 32],
+    pub to_chain_id: [u8; 8],
+    pub deadline: [u8; 8],
+}
+
+impl TokenApply {
+    pub fn check_open(
+        &self,
+        global: &GlobalAccount,
+        token_config: &TokenConfig,
+        chain_config: &ChainConfig,
+        route: &TokenRoute,
+    ) -> Result<()> {
+        if global.applies_paused || token_config.applies_paused {
+            return Err(BridgeErrors::BridgePaused.into());
+        }
+        if !token_config.enabled {
+            return Err(BridgeErrors::UnsupportedToken.into());
+        }
+        if !chain_config.enabled || !route.enabled {
+            return Err(BridgeErrors::UnsupportedRoute.into());
+        }
+        if Clock::get()?.unix_timestamp > i64::from_be_bytes(self.deadline) {
+            return Err(BridgeErrors::TransactionExpired.into());
+        }
+        Ok(())
+    }
 
+    /// Verifies the validator signatures over the apply message. With a
+    /// `legacy_amount` in local units, the legacy layout is accepted as well
+    /// while the global account allows it.
+    pub fn verify(
+        &self,
+        ix_sysvar: &AccountInfo,
+        validator_set: &ValidatorSet,
+        global: &GlobalAccount,
+        legacy_amount: Option<u64>,
+    ) -> Result<Layout> {
+        let scheme = validator_set.scheme;
+        let msg = crate::messages::apply_token_message(
+            scheme,
+            &global.message_domain(),
+            &self.order_id,
+            &self.applicant,
+            &self.receipient,
+            &self.from_chain_id,
+            &self.from_token,
+            &self.amount,
+            &self.to_chain_id,
+            &self.deadline,
+        );
+        let now = Clock::get()?.unix_timestamp;
+        let legacy_msg = legacy_amount
+            .filter(|_| global.accepts_legacy_messages(now))
+            .map(|amount| {
+                crate::messages::legacy_apply_token_message(
+                    scheme,
+                    &self.order_id,
+                    &self.applicant,
+                    &self.receipient,
+                    &self.from_chain_id,
+                    &self.from_token,
+                    &amount.to_be_bytes(),
+                    &self.to_chain_id,
+                    &self.deadline,
+                )
+            });
+        verify_order_signatures(ix_sysvar, validator_set, &msg, legacy_msg.as_deref())
+    }
 
+    pub fn check_source(&self, global: &GlobalAccount, applicant: &Pubkey, mint: &Pubkey) -> Result<()> {
+        if applicant.to_bytes() != self.applicant {
+            return Err(BridgeErrors::InvalidAccess.into());
+        }
+        if mint.to_bytes() != self.from_token {
+            return Err(BridgeErrors::InvalidFromToken.into());
+        }
+        if u64::from_be_bytes(self.from_chain_id) != global.chain_id {
+            return Err(BridgeErrors::InvalidFromChainId.into());
+        }
+        Ok(())
+    }
 
+    pub fn fill_order(
+        &self,
+        order: &mut OrderAccount,
+        direction: OrderDirection,
+        mint: Pubkey,
+        breakdown: &FeeBreakdown,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        order.order_id = self.order_id;
+        order.direction = direction;
+        order.status = OrderStatus::Completed;
+        order.applicant = self.applicant;
+        order.receipient = self.receipient;
+        order.from_chain_id = u64::from_be_bytes(self.from_chain_id);
+        order.to_chain_id = u64::from_be_bytes(self.to_chain_id);
+        order.token = self.from_token;
+        order.mint = mint;
+        order.amount = breakdown.net;
+        order.fee = breakdown.fee;
+        order.slot = clock.slot;
+        order.timestamp = clock.unix_timestamp;
+        Ok(())
+    }
+}
 
+/// The fields validators sign for a token claim, shared by `claim_token`,
+/// `claim_sol`, `deliver_token` and `claim_token_and_call`.
+pub struct TokenClaim {
+    pub order_id: [u8; 32],
+    pub applicant: [u8; 32],
+    pub receipient: [u8; 32],
+    pub from_chain_id: [u8; 8],
+    pub to_chain_id: [u8; 8],
+    pub to_token: [u8; 32],
+    pub amount: [u8; 32],
+    pub deadline: [u8; 8],
+}
 
-    let exp_signature_offset: u16 = exp_public_key_offset + pubkey.len() as u16;
-    let exp_message_data_offset: u16 = exp_signature_offset + sig.len() as u16;
-    let exp_num_signatures: u8 = 1;
-    let exp_message_data_size: u16 = msg.len().try_into().unwrap();
+impl TokenClaim {
+    pub fn check_open(&self, global: &GlobalAccount, token_config: &TokenConfig) -> Result<()> {
+        if global.claims_paused || token_config.claims_paused {
+            return Err(BridgeErrors::BridgePaused.into());
+        }
+        if !token_config.enabled {
+            return Err(BridgeErrors::UnsupportedToken.into());
+        }
+        if Clock::get()?.unix_timestamp > i64::from_be_bytes(self.deadline) {
+            return Err(BridgeErrors::TransactionExpired.into());
+        }
+        Ok(())
+    }
 
+    pub fn message(&self, scheme: SignatureScheme, domain: &Domain) -> Vec<u8> {
+        crate::messages::claim_token_message(
+            scheme,
+            domain,
+            &self.order_id,
+            &self.applicant,
+            &self.receipient,
+            &self.from_chain_id,
+            &self.to_chain_id,
+            &self.to_token,
+            &self.amount,
+            &self.deadline,
+        )
+    }
+
+    /// Verifies the validator signatures over `msg`. With a `legacy_amount`
+    /// in local units, the legacy claim layout is accepted as well while the
+    /// global account allows it.
+    pub fn verify(
+        &self,
+        ix_sysvar: &AccountInfo,
+        validator_set: &ValidatorSet,
+        global: &GlobalAccount,
+        msg: &[u8],
+        legacy_amount: Option<u64>,
+    ) -> Result<Layout> {
+        let now = Clock::get()?.unix_timestamp;
+        let legacy_msg = legacy_amount
+            .filter(|_| global.accepts_legacy_messages(now))
+            .map(|amount| {
+                crate::messages::legacy_claim_token_message(
+                    validator_set.scheme,
+                    &self.order_id,
+                    &self.applicant,
+                    &self.receipient,
+                    &self.to_chain_id,
+                    &self.to_token,
+                    &amount.to_be_bytes(),
+                    &self.deadline,
+                )
+            });
+        verify_order_signatures(ix_sysvar, validator_set, msg, legacy_msg.as_deref())
+    }
 
-    if num_signatures != &exp_num_signatures.to_le_bytes()
-        || padding != &[0]
-        || signature_offset != &exp_signature_offset.to_le_bytes()
-        || signature_instruction_index != &u16::MAX.to_le_bytes()
-        || public_key_offset != &exp_public_key_offset.to_le_bytes()
-        || public_key_instruction_index != &u16::MAX.to_le_bytes()
-        || message_data_offset != &exp_message_data_offset.to_le_bytes()
-        || message_data_size != &exp_message_data_size.to_le_bytes()
-        || message_instruction_index != &u16::MAX.to_le_bytes()
-    {
-        return Err(BridgeErrors::SignatureVerificationFailed.into());
+    pub fn check_destination(&self, global: &GlobalAccount, receipient: &Pubkey, mint: &Pubkey) -> Result<()> {
+        if receipient.to_bytes() != self.receipient {
+            return Err(BridgeErrors::InvalidAccess.into());
+        }
+        if mint.to_bytes() != self.to_token {
+            return Err(BridgeErrors::InvalidToToken.into());
+        }
+        if u64::from_be_bytes(self.to_chain_id) != global.chain_id {
+            return Err(BridgeErrors::InvalidToChainId.into());
+        }
+        Ok(())
     }
 
-    if data_pubkey != pubkey || data_msg != msg || data_sig != sig {
-        return Err(BridgeErrors::SignatureVerificationFailed.into());
+    /// Records the claim receipt. The legacy layout does not sign the source
+    /// chain, so it is only recorded for versioned claims.
+    pub fn fill_order(
+        &self,
+        order: &mut OrderAccount,
+        layout: Layout,
+        direction: OrderDirection,
+        mint: Pubkey,
+        amount: u64,
+        fee: u64,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        order.order_id = self.order_id;
+        order.direction = direction;
+        order.status = OrderStatus::Completed;
+        order.applicant = self.applicant;
+        order.receipient = self.receipient;
+        order.from_chain_id = match layout {
+            Layout::Versioned => u64::from_be_bytes(self.from_chain_id),
+            Layout::Legacy => 0,
+        };
+        order.to_chain_id = u64::from_be_bytes(self.to_chain_id);
+        order.token = self.to_token;
+        order.mint = mint;
+        order.amount = amount;
+        order.fee = fee;
+        order.slot = clock.slot;
+        order.timestamp = clock.unix_timestamp;
+        Ok(())
     }
+}
+
+/// Returns the public key of every signature in an Ed25519 program instruction
+/// that covers exactly `msg`, for instructions carrying all their data inline.
+pub fn check_ed25519_data(data: &[u8], msg: &[u8]) -> Result<Vec<[u8; 32]>> {
+    parse_ed25519_signers(data, msg, &|_| {
+        Err(BridgeErrors::SignatureVerificationFailed.into())
+    })
+}
+
+/// Returns the Ethereum address, left-padded to 32 bytes, of every signature in
+/// the Secp256k1 program instruction at `instruction_index` that covers exactly
+/// `msg`, for instructions carrying all their data inline.
+pub fn check_secp256k1_data(
+    data: &[u8],
+    msg: &[u8],
+    instruction_index: usize,
+) -> Result<Vec<[u8; 32]>> {
+    parse_secp256k1_signers(data, instruction_index, msg, &|_| {
+        Err(BridgeErrors::SignatureVerificationFailed.into())
+    })
+}
+
+fn parse_ed25519_signers(
+    data: &[u8],
+    msg: &[u8],
+    load_instruction_data: &dyn Fn(usize) -> Result<Vec<u8>>,
+) -> Result<Vec<[u8; 32]>> {
+    // According to this layout used by the Ed25519Program
+    // https://github.com/solana-labs/solana-web3.js/blob/master/src/ed25519-program.ts#L33
+    let num_signatures = *data.get(0).ok_or(BridgeErrors::SignatureVerificationFailed)? as usize;
+    let mut pubkeys = Vec::with_capacity(num_signatures);
+    for i in 0..num_signatures {
+        let start = SIGNATURE_OFFSETS_START + i * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        let offsets = data
+            .get(start..start + SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+            .ok_or(BridgeErrors::SignatureVerificationFailed)?;
+
+        // An instruction index of u16::MAX refers to the Ed25519 instruction itself.
+        let instruction = |index: u16| if index == u16::MAX { None } else { Some(index as usize) };
+        let public_key_offset = read_u16(offsets, 4) as usize;
+        let public_key_instruction = instruction(read_u16(offsets, 6));
+        let message_data_offset = read_u16(offsets, 8) as usize;
+        let message_data_size = read_u16(offsets, 10) as usize;
+        let message_instruction = instruction(read_u16(offsets, 12));
+
+        let data_msg = read_referenced(
+            data,
+            message_instruction,
+            message_data_offset,
+            message_data_size,
+            load_instruction_data,
+        )?;
+        if data_msg != msg {
+            continue;
+        }
+
+        let data_pubkey = read_referenced(
+            data,
+            public_key_instruction,
+            public_key_offset,
+            PUBKEY_SIZE,
+            load_instruction_data,
+        )?;
+        pubkeys.push(data_pubkey.as_slice().try_into().unwrap());
+    }
+
+    Ok(pubkeys)
+}
+
+fn parse_secp256k1_signers(
+    data: &[u8],
+    instruction_index: usize,
+    msg: &[u8],
+    load_instruction_data: &dyn Fn(usize) -> Result<Vec<u8>>,
+) -> Result<Vec<[u8; 32]>> {
+    // Layout used by the Secp256k1Program
+    // https://docs.solana.com/developing/runtime-facilities/programs#secp256k1-program
+    let num_signatures = *data.get(0).ok_or(BridgeErrors::SignatureVerificationFailed)? as usize;
+    let mut addresses = Vec::with_capacity(num_signatures);
+    for i in 0..num_signatures {
+        let start = SECP256K1_OFFSETS_START + i * SECP256K1_OFFSETS_SERIALIZED_SIZE;
+        let offsets = data
+            .get(start..start + SECP256K1_OFFSETS_SERIALIZED_SIZE)
+            .ok_or(BridgeErrors::SignatureVerificationFailed)?;
+
+        // Instruction indexes are absolute, the Secp256k1 instruction itself included.
+        let instruction = |index: u8| {
+            if index as usize == instruction_index {
+                None
+            } else {
+                Some(index as usize)
+            }
+        };
+        let eth_address_offset = read_u16(offsets, 3) as usize;
+        let eth_address_instruction = instruction(offsets[5]);
+        let message_data_offset = read_u16(offsets, 6) as usize;
+        let message_data_size = read_u16(offsets, 8) as usize;
+        let message_instruction = instruction(offsets[10]);
+
+        let data_msg = read_referenced(
+            data,
+            message_instruction,
+            message_data_offset,
+            message_data_size,
+            load_instruction_data,
+        )?;
+        if data_msg != msg {
+            continue;
+        }
+
+        let eth_address = read_referenced(
+            data,
+            eth_address_instruction,
+            eth_address_offset,
+            ETH_ADDRESS_SIZE,
+            load_instruction_data,
+        )?;
+        let mut address = [0u8; 32];
+        address[32 - ETH_ADDRESS_SIZE..].copy_from_slice(&eth_address);
+        addresses.push(address);
+    }
+
+    Ok(addresses)
+}
+
+/// Reads `size` bytes at `offset` from `data`, or from the data of another
+/// instruction of the transaction when `instruction` is set.
+fn read_referenced(
+    data: &[u8],
+    instruction: Option<usize>,
+    offset: usize,
+    size: usize,
+    load_instruction_data: &dyn Fn(usize) -> Result<Vec<u8>>,
+) -> Result<Vec<u8>> {
+    let referenced;
+    let source = match instruction {
+        Some(index) => {
+            referenced = load_instruction_data(index)?;
+            referenced.as_slice()
+        }
+        None => data,
+    };
+    Ok(source
+        .get(offset..offset + size)
+        .ok_or(BridgeErrors::SignatureVerificationFailed)?
+        .to_vec())
+}
 
+fn read_u16(bytes: &[u8], at: usize) -> u16 {
+    u16::from_le_bytes([bytes[at], bytes[at + 1]])
+}
+
+/// Decimals of the canonical amounts carried by signed messages.
+pub const CANONICAL_DECIMALS: u8 = 18;
+
+fn decimals_factor(decimals: u8) -> Result<u128> {
+    let exponent = (decimals as i32 - CANONICAL_DECIMALS as i32).unsigned_abs();
+    Ok(10u128.checked_pow(exponent).ok_or(BridgeErrors::MathOverflow)?)
+}
+
+fn canonical_value(amount: &[u8; 32]) -> Result<u128> {
+    if amount[..16].iter().any(|byte| *byte != 0) {
+        return Err(BridgeErrors::MathOverflow.into());
+    }
+    Ok(u128::from_be_bytes(amount[16..].try_into().unwrap()))
+}
+
+/// Converts a canonical amount to the smallest unit of a token with `decimals`,
+/// rejecting amounts that would lose precision.
+pub fn from_canonical_amount(amount: &[u8; 32], decimals: u8) -> Result<u64> {
+    let value = canonical_value(amount)?;
+    let factor = decimals_factor(decimals)?;
+    let local = if decimals <= CANONICAL_DECIMALS {
+        if value % factor != 0 {
+            return Err(BridgeErrors::AmountNotExact.into());
+        }
+        value / factor
+    } else {
+        value.checked_mul(factor).ok_or(BridgeErrors::MathOverflow)?
+    };
+    Ok(u64::try_from(local).map_err(|_| BridgeErrors::MathOverflow)?)
+}
+
+/// Converts an amount of a token with `decimals` to its canonical form.
+pub fn to_canonical_amount(amount: u64, decimals: u8) -> Result<[u8; 32]> {
+    let factor = decimals_factor(decimals)?;
+    let value = if decimals <= CANONICAL_DECIMALS {
+        (amount as u128).checked_mul(factor).ok_or(BridgeErrors::MathOverflow)?
+    } else {
+        if amount as u128 % factor != 0 {
+            return Err(BridgeErrors::AmountNotExact.into());
+        }
+        amount as u128 / factor
+    };
+    let mut canonical = [0u8; 32];
+    canonical[16..].copy_from_slice(&value.to_be_bytes());
+    Ok(canonical)
+}
+
+/// Fails unless a canonical amount is expressible with `decimals`, without
+/// bounding its size: remote tokens are not limited to `u64`.
+pub fn check_canonical_precision(amount: &[u8; 32], decimals: u8) -> Result<()> {
+    if decimals >= CANONICAL_DECIMALS {
+        return Ok(());
+    }
+    let factor = decimals_factor(decimals)?;
+    // amount = high * 2^128 + low, reduced modulo `factor` (at most 10^18)
+    // so that no step overflows.
+    let high = u128::from_be_bytes(amount[..16].try_into().unwrap());
+    let low = u128::from_be_bytes(amount[16..].try_into().unwrap());
+    let two_pow_128_mod = (u128::MAX % factor + 1) % factor;
+    let remainder = ((high % factor) * two_pow_128_mod % factor + low % factor) % factor;
+    if remainder != 0 {
+        return Err(BridgeErrors::AmountNotExact.into());
+    }
+    Ok(())
+}
+
+pub struct FeeBreakdown {
+    /// Amount moved from the user into the vault.
+    pub total: u64,
+    /// Amount credited on the destination chain.
+    pub net: u64,
+    pub fee: u64,
+}
+
+pub fn calculate_fee(amount: u64, fee_rate: u64, rounding: FeeRounding) -> Result<u64> {
+    let product = (amount as u128)
+        .checked_mul(fee_rate as u128)
+        .ok_or(BridgeErrors::MathOverflow)?;
+    let denominator = FEE_RATE_DENOMINATOR as u128;
+    let fee = match rounding {
+        FeeRounding::Up => (product + denominator - 1) / denominator,
+        FeeRounding::Down => product / denominator,
+    };
+    Ok(u64::try_from(fee).map_err(|_| BridgeErrors::MathOverflow)?)
+}
+
+pub fn split_fee(amount: u64, fee_rate: u64, mode: FeeMode, rounding: FeeRounding) -> Result<FeeBreakdown> {
+    let fee = calculate_fee(amount, fee_rate, rounding)?;
+    let breakdown = match mode {
+        FeeMode::OnTop => FeeBreakdown {
+            total: amount.checked_add(fee).ok_or(BridgeErrors::MathOverflow)?,
+            net: amount,
+            fee,
+        },
+        FeeMode::Deducted => FeeBreakdown {
+            total: amount,
+            net: amount.checked_sub(fee).ok_or(BridgeErrors::MathOverflow)?,
+            fee,
+        },
+    };
+    Ok(breakdown)
+}
+
+/// Splits the bridge fee off an applied `amount`, at the token's rate, else
+/// the destination chain's, else the global one. The bridged amount must
+/// arrive on the destination chain without dust.
+pub fn split_apply_fee(
+    global: &GlobalAccount,
+    token_config: &TokenConfig,
+    chain_config: &ChainConfig,
+    route: &TokenRoute,
+    amount: u64,
+    decimals: u8,
+) -> Result<FeeBreakdown> {
+    let fee_rate = token_config
+        .fee_rate_override
+        .or(chain_config.fee_rate_override)
+        .unwrap_or(global.token_fee_rate);
+    let breakdown = split_fee(amount, fee_rate, global.fee_mode, global.fee_rounding)?;
+    check_canonical_precision(&to_canonical_amount(breakdown.net, decimals)?, route.remote_decimals)?;
+    Ok(breakdown)
+}
+
+pub fn check_token_settings(fee_rate_override: Option<u64>, min_amount: u64, max_amount: u64) -> Result<()> {
+    if fee_rate_override.map_or(false, |rate| rate > FEE_RATE_DENOMINATOR) {
+        return Err(BridgeErrors::InvalidFeeRate.into());
+    }
+    if max_amount > 0 && min_amount > max_amount {
+        return Err(BridgeErrors::InvalidAmountLimits.into());
+    }
     Ok(())
 }
 
@@ -69,3 +628,371 @@ pub fn transer_to_user<'info, T: Id + Clone>(
     )?;
     Ok(())
 }
+
+pub fn mint_to_user<'info, T: Id + Clone>(
+    token_program: &Program<'info, T>,
+    mint: &Account<'info, Mint>,
+    mint_authority: &AccountInfo<'info>,
+    user_token_account: &Account<'info, TokenAccount>,
+    amount: u64,
+) -> Result<()> {
+    let (pda, bump) = Pubkey::find_program_address(&[b"mint-authority", mint.key().as_ref()], &crate::id());
+    if pda != mint_authority.key() {
+        return Err(super::errors::BridgeErrors::InvalidMintAuthority.into());
+    }
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: mint.to_account_info(),
+                to: user_token_account.to_account_info(),
+                authority: mint_authority.clone(),
+            },
+            &[&[b"mint-authority", mint.key().as_ref(), &[bump]]],
+        ),
+        amount,
+    )?;
+    Ok(())
+}
+
+/// Pays `amount` of a claimed token to `user_token_account`, from the vault
+/// liquidity for `LockUnlock` tokens or freshly minted for `MintBurn` tokens.
+pub fn release_tokens<'info, T: Id + Clone>(
+    token_program: &Program<'info, T>,
+    token_config: &TokenConfig,
+    vault: &Account<'info, TokenAccount>,
+    mint: &Account<'info, Mint>,
+    mint_authority: &AccountInfo<'info>,
+    user_token_account: &Account<'info, TokenAccount>,
+    amount: u64,
+) -> Result<()> {
+    match token_config.mode {
+        TokenMode::LockUnlock => {
+            let liquidity = vault.amount.saturating_sub(token_config.accrued_fees);
+            if amount > liquidity {
+                return Err(BridgeErrors::InsufficientLiquidity.into());
+            }
+            transer_to_user(token_program, vault, user_token_account, amount)
+        }
+        TokenMode::MintBurn => mint_to_user(token_program, mint, mint_authority, user_token_account, amount),
+    }
+}
+
+/// Sends the NFT held by its escrow to `user_token_account`.
+pub fn release_nft<'info, T: Id + Clone>(
+    token_program: &Program<'info, T>,
+    escrow: &Account<'info, TokenAccount>,
+    user_token_account: &Account<'info, TokenAccount>,
+) -> Result<()> {
+    let (pda, bump) = Pubkey::find_program_address(&[b"nft-escrow", escrow.mint.as_ref()], &crate::id());
+    if pda != escrow.key() {
+        return Err(BridgeErrors::InvalidVaultPDA.into());
+    }
+    if escrow.amount == 0 {
+        return Err(BridgeErrors::InsufficientLiquidity.into());
+    }
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: escrow.to_account_info(),
+                to: user_token_account.to_account_info(),
+                authority: escrow.to_account_info(),
+            },
+            &[&[b"nft-escrow", escrow.mint.as_ref(), &[bump]]],
+        ),
+        1,
+    )?;
+    Ok(())
+}
+
+/// Moves `amount` lamports from `from` into the wSOL vault and syncs its token balance.
+pub fn wrap_sol<'info, T: Id + Clone>(
+    token_program: &Program<'info, T>,
+    system_program: &Program<'info, System>,
+    from: &Signer<'info>,
+    vault: &Account<'info, TokenAccount>,
+    amount: u64,
+) -> Result<()> {
+    invoke(
+        &system_instruction::transfer(from.key, &vault.key(), amount),
+        &[from.to_account_info(), vault.to_account_info(), system_program.to_account_info()],
+    )?;
+    invoke(
+        &spl_token::instruction::sync_native(&spl_token::ID, &vault.key())?,
+        &[vault.to_account_info(), token_program.to_account_info()],
+    )?;
+    Ok(())
+}
+
+/// Pays `amount` of wSOL out of the vault as lamports: the wSOL is moved into
+/// the temporary `unwrap_account`, which is then closed into `to`. Closing
+/// also returns the rent of `unwrap_account` to `to`.
+pub fn unwrap_sol<'info, T: Id + Clone>(
+    token_program: &Program<'info, T>,
+    vault: &Account<'info, TokenAccount>,
+    unwrap_account: &Account<'info, TokenAccount>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    transer_to_user(token_program, vault, unwrap_account, amount)?;
+    let (_, bump) = Pubkey::find_program_address(&[b"vault", vault.mint.as_ref()], &crate::id());
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: unwrap_account.to_account_info(),
+            destination: to.clone(),
+            authority: vault.to_account_info(),
+        },
+        &[&[b"vault", vault.mint.as_ref(), &[bump]]],
+    ))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_error;
+
+    #[test]
+    fn fee_rounding() {
+        // 1 * 0.3% is 0.003 of a unit.
+        assert_eq!(calculate_fee(1, 3_000_000, FeeRounding::Up).unwrap(), 1);
+        assert_eq!(calculate_fee(1, 3_000_000, FeeRounding::Down).unwrap(), 0);
+        // Exact fees are not rounded in either direction.
+        assert_eq!(calculate_fee(1_000, 3_000_000, FeeRounding::Up).unwrap(), 3);
+        assert_eq!(calculate_fee(1_000, 3_000_000, FeeRounding::Down).unwrap(), 3);
+        assert_eq!(calculate_fee(u64::MAX, 0, FeeRounding::Up).unwrap(), 0);
+    }
+
+    #[test]
+    fn fee_at_the_u64_boundary() {
+        assert_eq!(
+            calculate_fee(u64::MAX, FEE_RATE_DENOMINATOR, FeeRounding::Up).unwrap(),
+            u64::MAX
+        );
+        // u64::MAX = 18446744073709551615, so 0.3% of it is 55340232221128654.845.
+        assert_eq!(
+            calculate_fee(u64::MAX, 3_000_000, FeeRounding::Up).unwrap(),
+            55_340_232_221_128_655
+        );
+        assert_eq!(
+            calculate_fee(u64::MAX, 3_000_000, FeeRounding::Down).unwrap(),
+            55_340_232_221_128_654
+        );
+    }
+
+    #[test]
+    fn split_fee_on_top() {
+        let breakdown = split_fee(1_000, 3_000_000, FeeMode::OnTop, FeeRounding::Up).unwrap();
+        assert_eq!((breakdown.total, breakdown.net, breakdown.fee), (1_003, 1_000, 3));
+
+        // At a 100% rate the user pays twice the amount.
+        let breakdown = split_fee(u64::MAX / 2, FEE_RATE_DENOMINATOR, FeeMode::OnTop, FeeRounding::Down).unwrap();
+        assert_eq!((breakdown.total, breakdown.fee), (u64::MAX - 1, u64::MAX / 2));
+        assert_error(
+            split_fee(u64::MAX / 2 + 1, FEE_RATE_DENOMINATOR, FeeMode::OnTop, FeeRounding::Down).map(|b| b.total),
+            BridgeErrors::MathOverflow,
+        );
+        assert_error(
+            split_fee(u64::MAX, 3_000_000, FeeMode::OnTop, FeeRounding::Down).map(|b| b.total),
+            BridgeErrors::MathOverflow,
+        );
+    }
+
+    #[test]
+    fn split_fee_deducted() {
+        let breakdown = split_fee(1_000, 3_000_000, FeeMode::Deducted, FeeRounding::Up).unwrap();
+        assert_eq!((breakdown.total, breakdown.net, breakdown.fee), (1_000, 997, 3));
+
+        let breakdown = split_fee(u64::MAX, 3_000_000, FeeMode::Deducted, FeeRounding::Up).unwrap();
+        assert_eq!(breakdown.total, u64::MAX);
+        assert_eq!(breakdown.fee, 55_340_232_221_128_655);
+        assert_eq!(breakdown.net, u64::MAX - 55_340_232_221_128_655);
+
+        let breakdown = split_fee(u64::MAX, FEE_RATE_DENOMINATOR, FeeMode::Deducted, FeeRounding::Up).unwrap();
+        assert_eq!((breakdown.net, breakdown.fee), (0, u64::MAX));
+    }
+
+    /// Ed25519 instruction data with one signature whose public key and
+    /// message live at the given instruction indexes and offsets.
+    fn ed25519_data(
+        public_key: (u16, u16),
+        message: (u16, u16, u16),
+        inline: &[u8],
+    ) -> Vec<u8> {
+        let mut data = vec![1, 0];
+        for value in [0, u16::MAX, public_key.1, public_key.0, message.1, message.2, message.0] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(inline);
+        data
+    }
+
+    /// Secp256k1 instruction data with one signature whose address and message
+    /// live at the given instruction indexes and offsets.
+    fn secp256k1_data(address: (u8, u16), message: (u8, u16, u16), inline: &[u8]) -> Vec<u8> {
+        let mut data = vec![1];
+        data.extend_from_slice(&0u16.to_le_bytes());
+        data.push(0);
+        data.extend_from_slice(&address.1.to_le_bytes());
+        data.push(address.0);
+        data.extend_from_slice(&message.1.to_le_bytes());
+        data.extend_from_slice(&message.2.to_le_bytes());
+        data.push(message.0);
+        data.extend_from_slice(inline);
+        data
+    }
+
+    fn no_instructions(_: usize) -> Result<Vec<u8>> {
+        Err(BridgeErrors::SignatureVerificationFailed.into())
+    }
+
+    #[test]
+    fn ed25519_inline_data() {
+        let pubkey = [5u8; 32];
+        let msg = b"order";
+        let inline = [&pubkey[..], msg].concat();
+        // The offsets block ends at byte 16.
+        let data = ed25519_data((u16::MAX, 16), (u16::MAX, 48, 5), &inline);
+        assert_eq!(check_ed25519_data(&data, msg).unwrap(), vec![pubkey]);
+        assert!(check_ed25519_data(&data, b"other").unwrap().is_empty());
+    }
+
+    #[test]
+    fn ed25519_cross_instruction_offsets() {
+        let pubkey = [5u8; 32];
+        let msg = b"order";
+        let load = |index: usize| -> Result<Vec<u8>> {
+            match index {
+                1 => Ok([&[0u8; 3][..], &pubkey].concat()),
+                3 => Ok([&[0u8; 7][..], msg].concat()),
+                _ => no_instructions(index),
+            }
+        };
+        let data = ed25519_data((1, 3), (3, 7, 5), &[]);
+        assert_eq!(parse_ed25519_signers(&data, msg, &load).unwrap(), vec![pubkey]);
+
+        // Past the end of the referenced instruction.
+        let data = ed25519_data((1, 4), (3, 7, 5), &[]);
+        assert_error(parse_ed25519_signers(&data, msg, &load), BridgeErrors::SignatureVerificationFailed);
+        let data = ed25519_data((1, 3), (3, 8, 5), &[]);
+        assert_error(parse_ed25519_signers(&data, msg, &load), BridgeErrors::SignatureVerificationFailed);
+        // An instruction that does not exist.
+        let data = ed25519_data((2, 3), (3, 7, 5), &[]);
+        assert_error(parse_ed25519_signers(&data, msg, &load), BridgeErrors::SignatureVerificationFailed);
+    }
+
+    #[test]
+    fn ed25519_out_of_range_offsets() {
+        let msg = b"order";
+        for data in [
+            vec![],
+            // Claims two signatures but carries a single offsets block.
+            [&[2u8][..], &ed25519_data((u16::MAX, 16), (u16::MAX, 48, 5), &[])[1..]].concat(),
+            ed25519_data((u16::MAX, 16), (u16::MAX, u16::MAX, u16::MAX), &[]),
+            ed25519_data((u16::MAX, u16::MAX), (u16::MAX, 16, 5), msg),
+        ] {
+            assert_error(check_ed25519_data(&data, msg), BridgeErrors::SignatureVerificationFailed);
+        }
+    }
+
+    #[test]
+    fn secp256k1_cross_instruction_offsets() {
+        let address = [6u8; 20];
+        let msg = b"order";
+        let load = |index: usize| -> Result<Vec<u8>> {
+            match index {
+                0 => Ok([&[0u8; 2][..], &address].concat()),
+                _ => no_instructions(index),
+            }
+        };
+        // The instruction is at index 2, so index 2 refers to its own data,
+        // which starts the message right after the 12-byte header.
+        let data = secp256k1_data((0, 2), (2, 12, 5), msg);
+        let mut padded = [0u8; 32];
+        padded[12..].copy_from_slice(&address);
+        assert_eq!(parse_secp256k1_signers(&data, 2, msg, &load).unwrap(), vec![padded]);
+
+        let data = secp256k1_data((0, 3), (2, 12, 5), msg);
+        assert_error(parse_secp256k1_signers(&data, 2, msg, &load), BridgeErrors::SignatureVerificationFailed);
+        let data = secp256k1_data((1, 2), (2, 12, 5), msg);
+        assert_error(parse_secp256k1_signers(&data, 2, msg, &load), BridgeErrors::SignatureVerificationFailed);
+    }
+
+    #[test]
+    fn secp256k1_out_of_range_offsets() {
+        let address = [6u8; 20];
+        let msg = b"order";
+        let inline = [&address[..], msg].concat();
+        let data = secp256k1_data((0, 12), (0, 32, 5), &inline);
+        assert_eq!(check_secp256k1_data(&data, msg, 0).unwrap().len(), 1);
+
+        for data in [
+            vec![],
+            vec![1, 0, 0],
+            secp256k1_data((0, 12), (0, u16::MAX, u16::MAX), &inline),
+            secp256k1_data((0, 20), (0, 32, 5), &inline),
+        ] {
+            assert_error(check_secp256k1_data(&data, msg, 0), BridgeErrors::SignatureVerificationFailed);
+        }
+    }
+
+    fn canonical(value: u128) -> [u8; 32] {
+        let mut amount = [0u8; 32];
+        amount[16..].copy_from_slice(&value.to_be_bytes());
+        amount
+    }
+
+    #[test]
+    fn canonical_amount_round_trip() {
+        // 1.5 tokens with 6 decimals.
+        let amount = to_canonical_amount(1_500_000, 6).unwrap();
+        assert_eq!(amount, canonical(1_500_000_000_000_000_000));
+        assert_eq!(from_canonical_amount(&amount, 6).unwrap(), 1_500_000);
+        assert_eq!(from_canonical_amount(&amount, 18).unwrap(), 1_500_000_000_000_000_000);
+        assert_eq!(to_canonical_amount(u64::MAX, 18).unwrap(), canonical(u64::MAX as u128));
+        assert_eq!(
+            from_canonical_amount(&to_canonical_amount(u64::MAX, 0).unwrap(), 0).unwrap(),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn canonical_dust_is_rejected() {
+        let dusty = canonical(1_500_000_000_000_000_001);
+        assert_error(from_canonical_amount(&dusty, 6), BridgeErrors::AmountNotExact);
+        assert_error(check_canonical_precision(&dusty, 6), BridgeErrors::AmountNotExact);
+        assert_eq!(from_canonical_amount(&dusty, 18).unwrap(), 1_500_000_000_000_000_001);
+        check_canonical_precision(&dusty, 18).unwrap();
+        check_canonical_precision(&canonical(1_500_000_000_000_000_000), 6).unwrap();
+
+        // Tokens with more decimals than the canonical form lose the extra digits.
+        assert_error(to_canonical_amount(1_001, 21), BridgeErrors::AmountNotExact);
+        assert_eq!(to_canonical_amount(1_000, 21).unwrap(), canonical(1));
+    }
+
+    #[test]
+    fn canonical_amounts_beyond_u64() {
+        const SCALE: u128 = 1_000_000_000_000;
+        // 2^64 base units of an 18-decimal token.
+        let amount = canonical(u64::MAX as u128 + 1);
+        assert_error(from_canonical_amount(&amount, 18), BridgeErrors::MathOverflow);
+        check_canonical_precision(&amount, 18).unwrap();
+        // The same bound after scaling down to 6 decimals.
+        let amount = canonical(u64::MAX as u128 * SCALE);
+        assert_eq!(from_canonical_amount(&amount, 6).unwrap(), u64::MAX);
+        let amount = canonical((u64::MAX as u128 + 1) * SCALE);
+        assert_error(from_canonical_amount(&amount, 6), BridgeErrors::MathOverflow);
+
+        // Amounts of 2^128 and above are valid remotely but never fit locally.
+        let mut amount = [0u8; 32];
+        amount[..16].copy_from_slice(&SCALE.to_be_bytes());
+        check_canonical_precision(&amount, 6).unwrap();
+        assert_error(from_canonical_amount(&amount, 6), BridgeErrors::MathOverflow);
+        amount[31] = 1;
+        assert_error(check_canonical_precision(&amount, 6), BridgeErrors::AmountNotExact);
+
+        assert_error(to_canonical_amount(1, 57), BridgeErrors::MathOverflow);
+    }
+}
@@ -0,0 +1,832 @@
+use anchor_lang::error::ErrorCode;
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use bridge::errors::BridgeErrors;
+use bridge::messages::{self, Domain};
+use bridge::program_accounts::{
+    OrderAccount, OrderDirection, SignatureScheme, TokenMode, MIN_EMERGENCY_WITHDRAWAL_DELAY,
+};
+use bridge::utils::to_canonical_amount;
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::account::create_account_shared_data_for_test;
+use solana_sdk::clock::Clock;
+use solana_sdk::ed25519_instruction::new_ed25519_instruction;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+use solana_sdk::transport::TransportError;
+use solana_sdk::{system_instruction, system_program, sysvar};
+
+const LOCAL_CHAIN: u64 = 101;
+const REMOTE_CHAIN: u64 = 56;
+const REMOTE_TOKEN: [u8; 32] = [9; 32];
+const DECIMALS: u8 = 6;
+const REMOTE_DECIMALS: u8 = 18;
+
+struct Bridge {
+    ctx: ProgramTestContext,
+    validator: ed25519_dalek::Keypair,
+    mint: Pubkey,
+    user_token_account: Pubkey,
+    now: i64,
+}
+
+struct Order {
+    order_id: [u8; 32],
+    applicant: [u8; 32],
+    receipient: [u8; 32],
+    token: [u8; 32],
+    amount: u64,
+    deadline: i64,
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &bridge::ID).0
+}
+
+fn validator_keypair(seed: u8) -> ed25519_dalek::Keypair {
+    let secret = ed25519_dalek::SecretKey::from_bytes(&[seed; 32]).unwrap();
+    let public = ed25519_dalek::PublicKey::from(&secret);
+    ed25519_dalek::Keypair { secret, public }
+}
+
+fn domain() -> Domain {
+    Domain {
+        program_id: bridge::ID,
+        chain_id: LOCAL_CHAIN,
+    }
+}
+
+fn custom_error(result: Result<(), TransportError>) -> u32 {
+    match result {
+        Err(TransportError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        ))) => code,
+        other => panic!("expected a custom program error, got {:?}", other),
+    }
+}
+
+impl Bridge {
+    async fn start() -> Self {
+        let program_test = ProgramTest::new("bridge", bridge::ID, processor!(bridge::entry));
+        let mut ctx = program_test.start_with_context().await;
+        let validator = validator_keypair(1);
+        let payer = ctx.payer.pubkey();
+        let now = ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+
+        let mint = Keypair::new();
+        let user_token_account = Keypair::new();
+        let rent = ctx.banks_client.get_rent().await.unwrap();
+        let mut bridge = Bridge {
+            ctx,
+            validator,
+            mint: mint.pubkey(),
+            user_token_account: user_token_account.pubkey(),
+            now,
+        };
+        bridge
+            .process(
+                vec![
+                    system_instruction::create_account(
+                        &payer,
+                        &mint.pubkey(),
+                        rent.minimum_balance(spl_token::state::Mint::LEN),
+                        spl_token::state::Mint::LEN as u64,
+                        &spl_token::ID,
+                    ),
+                    spl_token::instruction::initialize_mint(&spl_token::ID, &mint.pubkey(), &payer, None, DECIMALS)
+                        .unwrap(),
+                    system_instruction::create_account(
+                        &payer,
+                        &user_token_account.pubkey(),
+                        rent.minimum_balance(spl_token::state::Account::LEN),
+                        spl_token::state::Account::LEN as u64,
+                        &spl_token::ID,
+                    ),
+                    spl_token::instruction::initialize_account(
+                        &spl_token::ID,
+                        &user_token_account.pubkey(),
+                        &mint.pubkey(),
+                        &payer,
+                    )
+                    .unwrap(),
+                    spl_token::instruction::mint_to(
+                        &spl_token::ID,
+                        &mint.pubkey(),
+                        &user_token_account.pubkey(),
+                        &payer,
+                        &[],
+                        1_000_000,
+                    )
+                    .unwrap(),
+                ],
+                &[&mint, &user_token_account],
+            )
+            .await
+            .unwrap();
+
+        let validator_key = bridge.validator.public.to_bytes();
+        let initialize = bridge.instruction(
+            bridge::accounts::Initialize {
+                global_account: pda(&[b"global-account-02"]),
+                validator_set: pda(&[b"validator-set"]),
+                payer,
+                system_program: system_program::ID,
+            },
+            bridge::instruction::Initialize {
+                signer: validator_key,
+                token_fee_rate: 0,
+                chain_id: LOCAL_CHAIN,
+            },
+        );
+        let register_token = bridge.instruction(
+            bridge::accounts::RegisterToken {
+                global_account: pda(&[b"global-account-02"]),
+                vault: bridge.vault(),
+                token_config: pda(&[b"token-config", bridge.mint.as_ref()]),
+                token_registry: pda(&[b"token-registry"]),
+                mint: bridge.mint,
+                admin: payer,
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                rent: sysvar::rent::ID,
+            },
+            bridge::instruction::RegisterToken {
+                fee_rate_override: None,
+                min_amount: 0,
+                max_amount: 0,
+            },
+        );
+        let chain_id = REMOTE_CHAIN.to_be_bytes();
+        let register_chain = bridge.instruction(
+            bridge::accounts::RegisterChain {
+                global_account: pda(&[b"global-account-02"]),
+                chain_config: pda(&[b"chain", &chain_id]),
+                admin: payer,
+                system_program: system_program::ID,
+            },
+            bridge::instruction::RegisterChain {
+                chain_id,
+                fee_rate_override: None,
+            },
+        );
+        let register_route = bridge.instruction(
+            bridge::accounts::RegisterRoute {
+                global_account: pda(&[b"global-account-02"]),
+                chain_config: pda(&[b"chain", &chain_id]),
+                route: pda(&[b"route", bridge.mint.as_ref(), &chain_id]),
+                mint: bridge.mint,
+                admin: payer,
+                system_program: system_program::ID,
+            },
+            bridge::instruction::RegisterRoute {
+                chain_id,
+                remote_token: REMOTE_TOKEN,
+                remote_decimals: REMOTE_DECIMALS,
+            },
+        );
+        bridge
+            .process(vec![initialize, register_token, register_chain, register_route], &[])
+            .await
+            .unwrap();
+        bridge
+    }
+
+    fn instruction(&self, accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+        Instruction {
+            program_id: bridge::ID,
+            accounts: accounts.to_account_metas(None),
+            data: data.data(),
+        }
+    }
+
+    async fn process(&mut self, instructions: Vec<Instruction>, signers: &[&Keypair]) -> Result<(), TransportError> {
+        let blockhash = self.ctx.banks_client.get_recent_blockhash().await.unwrap();
+        let mut all_signers = vec![&self.ctx.payer];
+        all_signers.extend_from_slice(signers);
+        let transaction =
+            Transaction::new_signed_with_payer(&instructions, Some(&self.ctx.payer.pubkey()), &all_signers, blockhash);
+        self.ctx.banks_client.process_transaction(transaction).await
+    }
+
+    fn user(&self) -> [u8; 32] {
+        self.ctx.payer.pubkey().to_bytes()
+    }
+
+    fn vault(&self) -> Pubkey {
+        pda(&[b"vault", self.mint.as_ref()])
+    }
+
+    async fn token_balance(&mut self, account: Pubkey) -> u64 {
+        let account = self.ctx.banks_client.get_account(account).await.unwrap().unwrap();
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+
+    fn apply_order(&self, order_id: u8) -> Order {
+        Order {
+            order_id: [order_id; 32],
+            applicant: self.user(),
+            receipient: [7; 32],
+            token: self.mint.to_bytes(),
+            amount: 1_000,
+            deadline: self.now + 600,
+        }
+    }
+
+    fn claim_order(&self, order_id: u8) -> Order {
+        Order {
+            order_id: [order_id; 32],
+            applicant: [7; 32],
+            receipient: self.user(),
+            token: self.mint.to_bytes(),
+            amount: 400,
+            deadline: self.now + 600,
+        }
+    }
+
+    fn apply_message(&self, order: &Order) -> Vec<u8> {
+        messages::apply_token_message(
+            SignatureScheme::Ed25519,
+            &domain(),
+            &order.order_id,
+            &order.applicant,
+            &order.receipient,
+            &LOCAL_CHAIN.to_be_bytes(),
+            &order.token,
+            &to_canonical_amount(order.amount, DECIMALS).unwrap(),
+            &REMOTE_CHAIN.to_be_bytes(),
+            &order.deadline.to_be_bytes(),
+        )
+    }
+
+    fn claim_message(&self, order: &Order) -> Vec<u8> {
+        messages::claim_token_message(
+            SignatureScheme::Ed25519,
+            &domain(),
+            &order.order_id,
+            &order.applicant,
+            &order.receipient,
+            &REMOTE_CHAIN.to_be_bytes(),
+            &LOCAL_CHAIN.to_be_bytes(),
+            &order.token,
+            &to_canonical_amount(order.amount, DECIMALS).unwrap(),
+            &order.deadline.to_be_bytes(),
+        )
+    }
+
+    async fn apply_with(
+        &mut self,
+        order: &Order,
+        signatures: Vec<Instruction>,
+        vault: Pubkey,
+    ) -> Result<(), TransportError> {
+        let chain_id = REMOTE_CHAIN.to_be_bytes();
+        let apply = self.instruction(
+            bridge::accounts::ApplyToken {
+                global_account: pda(&[b"global-account-02"]),
+                validator_set: pda(&[b"validator-set"]),
+                vault,
+                token_config: pda(&[b"token-config", self.mint.as_ref()]),
+                mint: self.mint,
+                chain_config: pda(&[b"chain", &chain_id]),
+                route: pda(&[b"route", self.mint.as_ref(), &chain_id]),
+                order: pda(&[b"apply-order", &order.order_id]),
+                user_token_account: self.user_token_account,
+                user: self.ctx.payer.pubkey(),
+                ix_sysvar: sysvar::instructions::ID,
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+            },
+            bridge::instruction::ApplyToken {
+                order_id: order.order_id,
+                applicant: order.applicant,
+                receipient: order.receipient,
+                from_chain_id: LOCAL_CHAIN.to_be_bytes(),
+                from_token: order.token,
+                amount: to_canonical_amount(order.amount, DECIMALS).unwrap(),
+                to_chain_id: chain_id,
+                deadline: order.deadline.to_be_bytes(),
+            },
+        );
+        let mut instructions = signatures;
+        instructions.push(apply);
+        self.process(instructions, &[]).await
+    }
+
+    async fn apply(&mut self, order: &Order) -> Result<(), TransportError> {
+        let signature = new_ed25519_instruction(&self.validator, &self.apply_message(order));
+        let vault = self.vault();
+        self.apply_with(order, vec![signature], vault).await
+    }
+
+    async fn claim(&mut self, order: &Order) -> Result<(), TransportError> {
+        let signature = new_ed25519_instruction(&self.validator, &self.claim_message(order));
+        let claim = self.instruction(
+            bridge::accounts::ClaimToken {
+                global_account: pda(&[b"global-account-02"]),
+                validator_set: pda(&[b"validator-set"]),
+                vault: self.vault(),
+                token_config: pda(&[b"token-config", self.mint.as_ref()]),
+                mint: self.mint,
+                mint_authority: pda(&[b"mint-authority", self.mint.as_ref()]),
+                order: pda(&[b"claim-order", &order.order_id]),
+                user_token_account: self.user_token_account,
+                user: self.ctx.payer.pubkey(),
+                ix_sysvar: sysvar::instructions::ID,
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+            },
+            bridge::instruction::ClaimToken {
+                order_id: order.order_id,
+                applicant: order.applicant,
+                receipient: order.receipient,
+                from_chain_id: REMOTE_CHAIN.to_be_bytes(),
+                to_chain_id: LOCAL_CHAIN.to_be_bytes(),
+                to_token: order.token,
+                amount: to_canonical_amount(order.amount, DECIMALS).unwrap(),
+                deadline: order.deadline.to_be_bytes(),
+            },
+        );
+        self.process(vec![signature, claim], &[]).await
+    }
+
+    async fn deliver(&mut self, order: &Order, signature: Instruction, relayer_fee: u64) -> Result<(), TransportError> {
+        let receipient = Pubkey::new_from_array(order.receipient);
+        let receipient_token_account = Pubkey::find_program_address(
+            &[receipient.as_ref(), spl_token::ID.as_ref(), self.mint.as_ref()],
+            &anchor_spl::associated_token::ID,
+        )
+        .0;
+        let deliver = self.instruction(
+            bridge::accounts::DeliverToken {
+                global_account: pda(&[b"global-account-02"]),
+                validator_set: pda(&[b"validator-set"]),
+                vault: self.vault(),
+                token_config: pda(&[b"token-config", self.mint.as_ref()]),
+                mint: self.mint,
+                mint_authority: pda(&[b"mint-authority", self.mint.as_ref()]),
+                order: pda(&[b"claim-order", &order.order_id]),
+                receipient_wallet: receipient,
+                receipient_token_account,
+                relayer_token_account: self.user_token_account,
+                relayer: self.ctx.payer.pubkey(),
+                ix_sysvar: sysvar::instructions::ID,
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+                associated_token_program: anchor_spl::associated_token::ID,
+                rent: sysvar::rent::ID,
+            },
+            bridge::instruction::DeliverToken {
+                order_id: order.order_id,
+                applicant: order.applicant,
+                receipient: order.receipient,
+                from_chain_id: REMOTE_CHAIN.to_be_bytes(),
+                to_chain_id: LOCAL_CHAIN.to_be_bytes(),
+                to_token: order.token,
+                amount: to_canonical_amount(order.amount, DECIMALS).unwrap(),
+                deadline: order.deadline.to_be_bytes(),
+                relayer_fee: to_canonical_amount(relayer_fee, DECIMALS).unwrap(),
+            },
+        );
+        self.process(vec![signature, deliver], &[]).await
+    }
+
+    async fn update_message_config(&mut self, legacy_messages_until: i64) {
+        let update = self.instruction(
+            bridge::accounts::UpdateMessageConfig {
+                global_account: pda(&[b"global-account-02"]),
+                admin: self.ctx.payer.pubkey(),
+            },
+            bridge::instruction::UpdateMessageConfig {
+                chain_id: LOCAL_CHAIN,
+                legacy_messages_until,
+            },
+        );
+        self.process(vec![update], &[]).await.unwrap();
+    }
+
+    async fn update_validators(&mut self, validators: &[&ed25519_dalek::Keypair], threshold: u8) {
+        let update = self.instruction(
+            bridge::accounts::UpdateSignatureScheme {
+                global_account: pda(&[b"global-account-02"]),
+                validator_set: pda(&[b"validator-set"]),
+                admin: self.ctx.payer.pubkey(),
+            },
+            bridge::instruction::UpdateSignatureScheme {
+                scheme: SignatureScheme::Ed25519,
+                validators: validators.iter().map(|validator| validator.public.to_bytes()).collect(),
+                threshold,
+            },
+        );
+        self.process(vec![update], &[]).await.unwrap();
+    }
+
+    async fn update_rate_limit(&mut self, max_claim_amount: u64, window_limit: u64, window_duration: i64) {
+        let update = self.instruction(
+            bridge::accounts::UpdateRateLimit {
+                global_account: pda(&[b"global-account-02"]),
+                token_config: pda(&[b"token-config", self.mint.as_ref()]),
+                admin: self.ctx.payer.pubkey(),
+            },
+            bridge::instruction::UpdateRateLimit {
+                max_claim_amount,
+                window_limit,
+                window_duration,
+            },
+        );
+        self.process(vec![update], &[]).await.unwrap();
+    }
+
+    async fn update_guardian(&mut self, guardian: Pubkey) {
+        let update = self.instruction(
+            bridge::accounts::UpdateGuardian {
+                global_account: pda(&[b"global-account-02"]),
+                admin: self.ctx.payer.pubkey(),
+            },
+            bridge::instruction::UpdateGuardian { guardian },
+        );
+        self.process(vec![update], &[]).await.unwrap();
+    }
+
+    async fn schedule_emergency_withdrawal(&mut self, amount: u64) {
+        let schedule = self.instruction(
+            bridge::accounts::ScheduleEmergencyWithdrawal {
+                global_account: pda(&[b"global-account-02"]),
+                vault: self.vault(),
+                emergency_withdrawal: pda(&[b"emergency-withdrawal", self.mint.as_ref()]),
+                destination: self.user_token_account,
+                admin: self.ctx.payer.pubkey(),
+                system_program: system_program::ID,
+            },
+            bridge::instruction::ScheduleEmergencyWithdrawal { amount },
+        );
+        self.process(vec![schedule], &[]).await.unwrap();
+    }
+
+    async fn execute_emergency_withdrawal(&mut self) -> Result<(), TransportError> {
+        let execute = self.instruction(
+            bridge::accounts::ExecuteEmergencyWithdrawal {
+                global_account: pda(&[b"global-account-02"]),
+                token_config: pda(&[b"token-config", self.mint.as_ref()]),
+                vault: self.vault(),
+                emergency_withdrawal: pda(&[b"emergency-withdrawal", self.mint.as_ref()]),
+                destination: self.user_token_account,
+                admin: self.ctx.payer.pubkey(),
+                token_program: spl_token::ID,
+            },
+            bridge::instruction::ExecuteEmergencyWithdrawal {},
+        );
+        self.process(vec![execute], &[]).await
+    }
+
+    async fn advance_clock(&mut self, seconds: i64) {
+        let mut clock = self.ctx.banks_client.get_sysvar::<Clock>().await.unwrap();
+        clock.unix_timestamp += seconds;
+        self.ctx
+            .set_account(&sysvar::clock::ID, &create_account_shared_data_for_test(&clock));
+        // Sysvars are cached per bank, so move to a new one to pick up the clock.
+        self.ctx.warp_to_slot(clock.slot + 2).unwrap();
+    }
+
+    async fn cancel_emergency_withdrawal(&mut self, authority: &Keypair) -> Result<(), TransportError> {
+        let cancel = self.instruction(
+            bridge::accounts::CancelEmergencyWithdrawal {
+                global_account: pda(&[b"global-account-02"]),
+                emergency_withdrawal: pda(&[b"emergency-withdrawal", self.mint.as_ref()]),
+                admin: self.ctx.payer.pubkey(),
+                authority: authority.pubkey(),
+            },
+            bridge::instruction::CancelEmergencyWithdrawal {},
+        );
+        self.process(vec![cancel], &[authority]).await
+    }
+}
+
+#[tokio::test]
+async fn apply_locks_tokens_and_records_order() {
+    let mut bridge = Bridge::start().await;
+    let order = bridge.apply_order(1);
+    bridge.apply(&order).await.unwrap();
+
+    let vault = bridge.vault();
+    assert_eq!(bridge.token_balance(vault).await, 1_000);
+    let user_token_account = bridge.user_token_account;
+    assert_eq!(bridge.token_balance(user_token_account).await, 999_000);
+
+    let account = bridge
+        .ctx
+        .banks_client
+        .get_account(pda(&[b"apply-order", &order.order_id]))
+        .await
+        .unwrap()
+        .unwrap();
+    let recorded = OrderAccount::try_deserialize(&mut &account.data[..]).unwrap();
+    assert_eq!(recorded.direction, OrderDirection::Apply);
+    assert_eq!(recorded.amount, 1_000);
+    assert_eq!(recorded.to_chain_id, REMOTE_CHAIN);
+}
+
+#[tokio::test]
+async fn claim_releases_tokens() {
+    let mut bridge = Bridge::start().await;
+    bridge.apply(&bridge.apply_order(1)).await.unwrap();
+    bridge.claim(&bridge.claim_order(2)).await.unwrap();
+
+    let vault = bridge.vault();
+    assert_eq!(bridge.token_balance(vault).await, 600);
+    let user_token_account = bridge.user_token_account;
+    assert_eq!(bridge.token_balance(user_token_account).await, 999_400);
+
+    let account = bridge.ctx.banks_client.get_account(pda(&[b"claim-order", &[2; 32]])).await.unwrap().unwrap();
+    let recorded = OrderAccount::try_deserialize(&mut &account.data[..]).unwrap();
+    assert_eq!(recorded.direction, OrderDirection::Claim);
+    assert_eq!(recorded.from_chain_id, REMOTE_CHAIN);
+    assert_eq!(recorded.to_chain_id, LOCAL_CHAIN);
+}
+
+#[tokio::test]
+async fn deliver_accepts_legacy_claims_only_without_a_relayer_fee() {
+    let mut bridge = Bridge::start().await;
+    bridge.apply(&bridge.apply_order(1)).await.unwrap();
+    bridge.update_message_config(bridge.now + 3_600).await;
+    let order = bridge.claim_order(2);
+    let legacy_message = messages::legacy_claim_token_message(
+        SignatureScheme::Ed25519,
+        &order.order_id,
+        &order.applicant,
+        &order.receipient,
+        &LOCAL_CHAIN.to_be_bytes(),
+        &order.token,
+        &order.amount.to_be_bytes(),
+        &order.deadline.to_be_bytes(),
+    );
+    let signature = new_ed25519_instruction(&bridge.validator, &legacy_message);
+
+    let result = bridge.deliver(&order, signature.clone(), 10).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::InsufficientSignatures));
+
+    bridge.deliver(&order, signature, 0).await.unwrap();
+    let vault = bridge.vault();
+    assert_eq!(bridge.token_balance(vault).await, 600);
+    let account = bridge.ctx.banks_client.get_account(pda(&[b"claim-order", &[2; 32]])).await.unwrap().unwrap();
+    let recorded = OrderAccount::try_deserialize(&mut &account.data[..]).unwrap();
+    assert_eq!(recorded.from_chain_id, 0);
+}
+
+#[tokio::test]
+async fn signature_from_unknown_key_is_rejected() {
+    let mut bridge = Bridge::start().await;
+    let order = bridge.apply_order(1);
+    let signature = new_ed25519_instruction(&validator_keypair(2), &bridge.apply_message(&order));
+    let vault = bridge.vault();
+    let result = bridge.apply_with(&order, vec![signature], vault).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::InsufficientSignatures));
+}
+
+#[tokio::test]
+async fn signature_over_other_fields_is_rejected() {
+    let mut bridge = Bridge::start().await;
+    let order = bridge.apply_order(1);
+    let mut signed = bridge.apply_order(1);
+    signed.amount = 1;
+    let signature = new_ed25519_instruction(&bridge.validator, &bridge.apply_message(&signed));
+    let vault = bridge.vault();
+    let result = bridge.apply_with(&order, vec![signature], vault).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::InsufficientSignatures));
+}
+
+#[tokio::test]
+async fn forged_signature_fails_verification() {
+    let mut bridge = Bridge::start().await;
+    let order = bridge.apply_order(1);
+    let mut signature = new_ed25519_instruction(&bridge.validator, &bridge.apply_message(&order));
+    // First signature byte, right after the offsets and the public key.
+    signature.data[16 + 32] ^= 0xff;
+    let vault = bridge.vault();
+    assert!(bridge.apply_with(&order, vec![signature], vault).await.is_err());
+}
+
+#[tokio::test]
+async fn expired_deadline_is_rejected() {
+    let mut bridge = Bridge::start().await;
+    let mut order = bridge.apply_order(1);
+    order.deadline = bridge.now - 1;
+    let result = bridge.apply(&order).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::TransactionExpired));
+
+    let mut claim = bridge.claim_order(2);
+    claim.deadline = bridge.now - 1;
+    let result = bridge.claim(&claim).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::TransactionExpired));
+}
+
+// Order ids are tracked by per-order PDAs rather than a list in a per-user
+// account, so a replayed id fails on the existing order account.
+#[tokio::test]
+async fn duplicate_order_id_is_rejected() {
+    let mut bridge = Bridge::start().await;
+    bridge.apply(&bridge.apply_order(1)).await.unwrap();
+    let mut replay = bridge.apply_order(1);
+    replay.amount = 500;
+    assert!(bridge.apply(&replay).await.is_err());
+
+    bridge.claim(&bridge.claim_order(2)).await.unwrap();
+    let mut replay = bridge.claim_order(2);
+    replay.amount = 100;
+    assert!(bridge.claim(&replay).await.is_err());
+
+    let vault = bridge.vault();
+    assert_eq!(bridge.token_balance(vault).await, 600);
+}
+
+#[tokio::test]
+async fn wrong_applicant_is_rejected() {
+    let mut bridge = Bridge::start().await;
+    let mut order = bridge.apply_order(1);
+    order.applicant = [8; 32];
+    let result = bridge.apply(&order).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::InvalidAccess));
+}
+
+#[tokio::test]
+async fn wrong_recipient_is_rejected() {
+    let mut bridge = Bridge::start().await;
+    bridge.apply(&bridge.apply_order(1)).await.unwrap();
+    let mut claim = bridge.claim_order(2);
+    claim.receipient = [8; 32];
+    let result = bridge.claim(&claim).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::InvalidAccess));
+}
+
+#[tokio::test]
+async fn vault_must_be_the_vault_pda() {
+    let mut bridge = Bridge::start().await;
+    let order = bridge.apply_order(1);
+    let signature = new_ed25519_instruction(&bridge.validator, &bridge.apply_message(&order));
+    // Any token account of the right mint, here the user's own. Apply and claim
+    // bind the vault with a `seeds` constraint, so Anchor rejects it before the
+    // handler runs.
+    let fake_vault = bridge.user_token_account;
+    let result = bridge.apply_with(&order, vec![signature], fake_vault).await;
+    assert_eq!(custom_error(result), u32::from(ErrorCode::ConstraintSeeds));
+
+    // Instructions that take the vault unconstrained rely on the program's own check.
+    let update_fee_rate = bridge.instruction(
+        bridge::accounts::UpdateTokenFeeRate {
+            global_account: pda(&[b"global-account-02"]),
+            admin: bridge.ctx.payer.pubkey(),
+        },
+        bridge::instruction::UpdateTokenFeeRate {
+            token_fee_rate: 10_000_000,
+        },
+    );
+    bridge.process(vec![update_fee_rate], &[]).await.unwrap();
+    bridge.apply(&order).await.unwrap();
+    let withdraw_fees = bridge.instruction(
+        bridge::accounts::WithdrawFees {
+            global_account: pda(&[b"global-account-02"]),
+            token_config: pda(&[b"token-config", bridge.mint.as_ref()]),
+            vault: fake_vault,
+            treasury_token_account: bridge.user_token_account,
+            admin: bridge.ctx.payer.pubkey(),
+            token_program: spl_token::ID,
+        },
+        bridge::instruction::WithdrawFees {},
+    );
+    let result = bridge.process(vec![withdraw_fees], &[]).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::InvalidVaultPDA));
+}
+
+#[tokio::test]
+async fn emergency_withdrawal_waits_for_the_delay() {
+    let mut bridge = Bridge::start().await;
+    bridge.apply(&bridge.apply_order(1)).await.unwrap();
+    bridge.schedule_emergency_withdrawal(500).await;
+
+    let result = bridge.execute_emergency_withdrawal().await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::WithdrawalNotReady));
+    let vault = bridge.vault();
+    assert_eq!(bridge.token_balance(vault).await, 1_000);
+}
+
+#[tokio::test]
+async fn emergency_withdrawal_executes_while_paused() {
+    let mut bridge = Bridge::start().await;
+    bridge.apply(&bridge.apply_order(1)).await.unwrap();
+    bridge.schedule_emergency_withdrawal(500).await;
+    let pause = bridge.instruction(
+        bridge::accounts::Pause {
+            global_account: pda(&[b"global-account-02"]),
+            authority: bridge.ctx.payer.pubkey(),
+        },
+        bridge::instruction::Pause {
+            applies: true,
+            claims: true,
+        },
+    );
+    bridge.process(vec![pause], &[]).await.unwrap();
+    bridge.advance_clock(MIN_EMERGENCY_WITHDRAWAL_DELAY).await;
+
+    bridge.execute_emergency_withdrawal().await.unwrap();
+    let vault = bridge.vault();
+    assert_eq!(bridge.token_balance(vault).await, 500);
+    let user_token_account = bridge.user_token_account;
+    assert_eq!(bridge.token_balance(user_token_account).await, 999_500);
+}
+
+#[tokio::test]
+async fn replaced_guardian_can_still_cancel_emergency_withdrawal() {
+    let mut bridge = Bridge::start().await;
+    bridge.apply(&bridge.apply_order(1)).await.unwrap();
+    let guardian = Keypair::new();
+    bridge.update_guardian(guardian.pubkey()).await;
+    bridge.schedule_emergency_withdrawal(500).await;
+    bridge.update_guardian(Keypair::new().pubkey()).await;
+
+    let result = bridge.cancel_emergency_withdrawal(&Keypair::new()).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::InvalidAccess));
+
+    bridge.cancel_emergency_withdrawal(&guardian).await.unwrap();
+    let withdrawal = pda(&[b"emergency-withdrawal", bridge.mint.as_ref()]);
+    assert!(bridge.ctx.banks_client.get_account(withdrawal).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn threshold_minus_one_signers_is_rejected() {
+    let mut bridge = Bridge::start().await;
+    let validators = [validator_keypair(1), validator_keypair(2), validator_keypair(3)];
+    bridge.update_validators(&validators.iter().collect::<Vec<_>>(), 2).await;
+    let order = bridge.apply_order(1);
+    let message = bridge.apply_message(&order);
+    let vault = bridge.vault();
+
+    let signatures = vec![new_ed25519_instruction(&validators[0], &message)];
+    let result = bridge.apply_with(&order, signatures, vault).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::InsufficientSignatures));
+
+    let signatures = vec![
+        new_ed25519_instruction(&validators[0], &message),
+        new_ed25519_instruction(&validators[2], &message),
+    ];
+    bridge.apply_with(&order, signatures, vault).await.unwrap();
+}
+
+#[tokio::test]
+async fn duplicate_signers_count_once() {
+    let mut bridge = Bridge::start().await;
+    let validators = [validator_keypair(1), validator_keypair(2), validator_keypair(3)];
+    bridge.update_validators(&validators.iter().collect::<Vec<_>>(), 2).await;
+    let order = bridge.apply_order(1);
+    let message = bridge.apply_message(&order);
+    let vault = bridge.vault();
+
+    let signatures = vec![
+        new_ed25519_instruction(&validators[1], &message),
+        new_ed25519_instruction(&validators[1], &message),
+    ];
+    let result = bridge.apply_with(&order, signatures, vault).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::InsufficientSignatures));
+}
+
+#[tokio::test]
+async fn claims_beyond_the_rate_limit_are_rejected() {
+    let mut bridge = Bridge::start().await;
+    bridge.apply(&bridge.apply_order(1)).await.unwrap();
+    bridge.update_rate_limit(300, 500, 3_600).await;
+
+    let result = bridge.claim(&bridge.claim_order(2)).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::ClaimAmountExceedsLimit));
+
+    let mut claim = bridge.claim_order(3);
+    claim.amount = 300;
+    bridge.claim(&claim).await.unwrap();
+    let mut claim = bridge.claim_order(4);
+    claim.amount = 250;
+    let result = bridge.claim(&claim).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::RateLimitExceeded));
+
+    let vault = bridge.vault();
+    assert_eq!(bridge.token_balance(vault).await, 700);
+}
+
+#[tokio::test]
+async fn token_mode_cannot_change_with_a_funded_vault() {
+    let mut bridge = Bridge::start().await;
+    bridge.apply(&bridge.apply_order(1)).await.unwrap();
+    let set_mode = bridge.instruction(
+        bridge::accounts::SetTokenMode {
+            global_account: pda(&[b"global-account-02"]),
+            token_config: pda(&[b"token-config", bridge.mint.as_ref()]),
+            mint: bridge.mint,
+            vault: bridge.vault(),
+            admin: bridge.ctx.payer.pubkey(),
+        },
+        bridge::instruction::SetTokenMode {
+            mode: TokenMode::MintBurn,
+        },
+    );
+    let result = bridge.process(vec![set_mode], &[]).await;
+    assert_eq!(custom_error(result), u32::from(BridgeErrors::VaultNotEmpty));
+}